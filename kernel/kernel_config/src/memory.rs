@@ -34,6 +34,10 @@ pub const ADDRESSABILITY_PER_P4_ENTRY: usize = 1 << (PAGE_SHIFT + P4_INDEX_SHIFT
 
 pub const MAX_VIRTUAL_ADDRESS: usize = 0xFFFF_FFFF_FFFF_FFFF;
 
+/// Value: 52. The number of bits that can be used in a physical address on x86_64;
+/// all higher bits of a valid physical address must be zero.
+pub const MAX_PHYSICAL_ADDRESS_BITS: usize = 52;
+
 pub const TEMPORARY_PAGE_VIRT_ADDR: usize = MAX_VIRTUAL_ADDRESS;
 
 /// Value: 512. 
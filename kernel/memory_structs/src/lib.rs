@@ -12,40 +12,190 @@ extern crate bit_field;
 #[cfg(target_arch = "x86_64")]
 extern crate entryflags_x86_64;
 extern crate zerocopy;
+extern crate heapless;
+#[macro_use] extern crate static_assertions;
+#[macro_use] extern crate bitflags;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+#[cfg(test)]
+#[macro_use] extern crate std;
+
+#[cfg(test)]
+mod test;
 
 use bit_field::BitField;
 use core::{
     fmt,
     iter::Step,
-    ops::{Add, AddAssign, Deref, DerefMut, RangeInclusive, Sub, SubAssign},
+    marker::PhantomData,
+    num::NonZeroUsize,
+    ops::{Add, AddAssign, Bound, Deref, DerefMut, RangeBounds, RangeInclusive, Sub, SubAssign},
 };
-use kernel_config::memory::{MAX_PAGE_NUMBER, PAGE_SIZE};
+use kernel_config::memory::{MAX_PAGE_NUMBER, MAX_PHYSICAL_ADDRESS_BITS, MAX_VIRTUAL_ADDRESS, PAGE_SIZE};
 #[cfg(target_arch = "x86_64")]
 use entryflags_x86_64::EntryFlags;
-use zerocopy::FromBytes;
+use zerocopy::{AsBytes, FromBytes};
+use heapless::ArrayLength;
+
+/// The number of bits that an address is shifted right by to get its page or frame number,
+/// i.e., `log2(PAGE_SIZE)`.
+pub use kernel_config::memory::PAGE_SHIFT;
+const_assert_eq!(1 << PAGE_SHIFT, PAGE_SIZE);
+
+/// The number of the `Frame` containing the highest physical address, based on `MAX_PHYSICAL_ADDRESS_BITS`.
+const MAX_FRAME_NUMBER: usize = ((1 << MAX_PHYSICAL_ADDRESS_BITS) - 1) >> PAGE_SHIFT;
+
+// `strict_add()` wraps page and frame numbers by masking them with `MAX_PAGE_NUMBER` and `MAX_FRAME_NUMBER`.
+const_assert!((MAX_PAGE_NUMBER as u128 + 1).is_power_of_two());
+const_assert!((MAX_FRAME_NUMBER as u128 + 1).is_power_of_two());
+
+/// Whether virtual addresses on this architecture must be in canonical form.
+///
+/// On x86_64, a canonical virtual address is one whose bits (64:48] are sign-extended from bit 47.
+/// On architectures without such a requirement, [`VirtualAddress::new()`] accepts any `usize`.
+pub const IS_CANONICAL_CHECK_NEEDED: bool = cfg!(target_arch = "x86_64");
+
+/// The error type returned by the fallible constructors and operations in this crate.
+///
+/// Its `Display` form is a human-readable message, which is also available via [`MemoryStructError::as_str()`].
+/// It can be converted into a `&'static str` for use with functions that return `Result<_, &'static str>`.
+///
+/// Note that these messages differ from the `&'static str` errors this crate used to return:
+/// they no longer begin with the name of the failing function, e.g., `"FrameRange::shifted_by_bytes(): "`,
+/// and errors that used to be worded separately for frames and pages or for different capacity-limited outputs
+/// now share one message. Only the address constructors' messages are unchanged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemoryStructError {
+    /// A virtual address was not canonical, i.e., bits 48-63 were not a sign-extension of bit 47.
+    NonCanonicalVirtualAddress,
+    /// A physical address had bits set above `MAX_PHYSICAL_ADDRESS_BITS`.
+    PhysicalAddressTooLarge,
+    /// Address arithmetic overflowed past the maximum address, page number, or frame number,
+    /// e.g., when shifting a range.
+    AddressOverflow,
+    /// An offset or address was not a multiple of `PAGE_SIZE`.
+    NotPageAligned,
+    /// A frame number exceeded the highest valid frame number.
+    FrameNumberTooLarge,
+    /// A page number exceeded `MAX_PAGE_NUMBER`.
+    PageNumberTooLarge,
+    /// A range's start virtual address came after its end virtual address.
+    InvalidVirtualRange,
+    /// A range's start physical address came after its end physical address.
+    InvalidPhysicalRange,
+    /// A sequence of frames or pages was not contiguous.
+    NotContiguous,
+    /// The virtual and physical spans of a section had different lengths.
+    MismatchedSpans,
+    /// The output collection did not have enough capacity to hold every result.
+    CapacityExceeded,
+}
+
+impl MemoryStructError {
+    /// Returns the human-readable message describing this error.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            MemoryStructError::NonCanonicalVirtualAddress => "VirtualAddress bits 48-63 must be a sign-extension of bit 47",
+            MemoryStructError::PhysicalAddressTooLarge    => "PhysicalAddress bits 52-63 must be zero",
+            MemoryStructError::AddressOverflow            => "address arithmetic overflowed",
+            MemoryStructError::NotPageAligned             => "offset must be a multiple of PAGE_SIZE",
+            MemoryStructError::FrameNumberTooLarge        => "frame number exceeds the highest valid frame",
+            MemoryStructError::PageNumberTooLarge         => "page number exceeds MAX_PAGE_NUMBER",
+            MemoryStructError::InvalidVirtualRange        => "start virtual address is after end virtual address",
+            MemoryStructError::InvalidPhysicalRange       => "start physical address is after end physical address",
+            MemoryStructError::NotContiguous              => "frames or pages were not contiguous",
+            MemoryStructError::MismatchedSpans            => "virtual and physical spans have different lengths",
+            MemoryStructError::CapacityExceeded           => "too many ranges for the output capacity",
+        }
+    }
+}
+
+impl fmt::Display for MemoryStructError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<MemoryStructError> for &'static str {
+    fn from(err: MemoryStructError) -> &'static str {
+        err.as_str()
+    }
+}
+
+/// Writes the given address `value` in the form `v0x1234`, with the given `prefix` character,
+/// padded according to the width, fill, and alignment of the formatter `f`.
+fn pad_address(f: &mut fmt::Formatter, prefix: char, value: usize) -> fmt::Result {
+    /// A stack buffer large enough for the prefix, "0x", and every hex digit of a 64-bit `usize`.
+    struct AddressBuf {
+        bytes: [u8; 19],
+        len: usize,
+    }
+    impl fmt::Write for AddressBuf {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    let mut buf = AddressBuf { bytes: [0; 19], len: 0 };
+    fmt::Write::write_fmt(&mut buf, format_args!("{}{:#X}", prefix, value))?;
+    // The buffer only ever contains ASCII characters written above.
+    f.pad(core::str::from_utf8(&buf.bytes[..buf.len]).map_err(|_| fmt::Error)?)
+}
 
 /// A virtual memory address, which is a `usize` under the hood.
+///
+/// The derived arithmetic and bitwise operators only accept another `VirtualAddress`,
+/// so a `VirtualAddress` cannot be mixed with a `PhysicalAddress`:
+/// ```compile_fail
+/// # extern crate memory_structs;
+/// # use memory_structs::{PhysicalAddress, VirtualAddress};
+/// let _ = VirtualAddress::zero() - PhysicalAddress::zero();
+/// ```
+/// ```compile_fail
+/// # extern crate memory_structs;
+/// # use memory_structs::{PhysicalAddress, VirtualAddress};
+/// let _ = VirtualAddress::zero() + PhysicalAddress::zero();
+/// ```
 #[derive(
     Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, 
     Binary, Octal, LowerHex, UpperHex, 
     BitAnd, BitOr, BitXor, BitAndAssign, BitOrAssign, BitXorAssign, 
     Add, Sub, AddAssign, SubAssign,
-    FromBytes,
+    FromBytes, AsBytes,
 )]
 #[repr(transparent)]
 pub struct VirtualAddress(usize);
 
 impl VirtualAddress {
+    /// The lowest representable `VirtualAddress`, `0`.
+    pub const MIN: VirtualAddress = VirtualAddress(0);
+
+    /// The highest representable `VirtualAddress`, `usize::MAX`, which is canonical.
+    pub const MAX: VirtualAddress = VirtualAddress::new_canonical(usize::MAX);
+
     /// Creates a new `VirtualAddress`,
     /// checking that the address is canonical,
     /// i.e., bits (64:48] are sign-extended from bit 47.
-    pub fn new(virt_addr: usize) -> Result<VirtualAddress, &'static str> {
-        match virt_addr.get_bits(47..64) {
-            0 | 0b1_1111_1111_1111_1111 => Ok(VirtualAddress(virt_addr)),
-            _ => Err("VirtualAddress bits 48-63 must be a sign-extension of bit 47"),
+    ///
+    /// This check is only performed if [`IS_CANONICAL_CHECK_NEEDED`] is true for this architecture.
+    pub fn new(virt_addr: usize) -> Result<VirtualAddress, MemoryStructError> {
+        if VirtualAddress::is_canonical(virt_addr) {
+            Ok(VirtualAddress(virt_addr))
+        } else {
+            Err(MemoryStructError::NonCanonicalVirtualAddress)
         }
     }
 
+    /// Returns `true` if the given `virt_addr` is a valid, canonical virtual address on this architecture.
+    ///
+    /// If [`IS_CANONICAL_CHECK_NEEDED`] is false, every address is considered canonical.
+    pub fn is_canonical(virt_addr: usize) -> bool {
+        !IS_CANONICAL_CHECK_NEEDED || matches!(virt_addr.get_bits(47..64), 0 | 0b1_1111_1111_1111_1111)
+    }
+
     /// Creates a new `VirtualAddress` that is guaranteed to be canonical
     /// by forcing the upper bits (64:48] to be sign-extended from bit 47.
     pub const fn new_canonical(virt_addr: usize) -> VirtualAddress {
@@ -69,6 +219,18 @@ impl VirtualAddress {
         self.0
     }
 
+    /// Returns this address as a `NonZeroUsize`, or `None` if this is the zero address,
+    /// e.g., to store a present address compactly in a niche-optimized `Option`.
+    pub const fn as_nonzero(&self) -> Option<NonZeroUsize> {
+        NonZeroUsize::new(self.0)
+    }
+
+    /// Creates a `VirtualAddress` from the given nonzero value,
+    /// which is made valid via [`VirtualAddress::new_canonical()`].
+    pub const fn from_nonzero(addr: NonZeroUsize) -> VirtualAddress {
+        VirtualAddress::new_canonical(addr.get())
+    }
+
     /// Returns the offset that this VirtualAddress specifies into its containing memory Page.
     ///
     /// For example, if the PAGE_SIZE is 4KiB, then this will return
@@ -76,15 +238,169 @@ impl VirtualAddress {
     pub const fn page_offset(&self) -> usize {
         self.0 & (PAGE_SIZE - 1)
     }
+
+    /// Returns the `Page` containing this `VirtualAddress`.
+    ///
+    /// This is equivalent to [`Page::containing_address()`].
+    pub const fn containing_page(&self) -> Page {
+        Page::containing_address(*self)
+    }
+
+    /// Returns the base address of the memory Page containing this VirtualAddress,
+    /// i.e., this VirtualAddress with its `page_offset()` bits cleared.
+    pub const fn page_base(&self) -> VirtualAddress {
+        VirtualAddress(self.0 & !(PAGE_SIZE - 1))
+    }
+
+    /// Returns the offset of this VirtualAddress into the given `PageRange`,
+    /// or `None` if this address is not covered by that range.
+    ///
+    /// This is equivalent to [`PageRange::offset_of_address()`].
+    pub fn offset_into(&self, range: &PageRange) -> Option<usize> {
+        range.offset_of_address(*self)
+    }
+
+    /// Returns this address moved by the signed `delta` number of bytes,
+    /// or `None` if that would overflow past the maximum address, underflow past `0`,
+    /// or otherwise produce an invalid `VirtualAddress`.
+    pub fn checked_offset(&self, delta: isize) -> Option<VirtualAddress> {
+        let addr = if delta >= 0 {
+            self.0.checked_add(delta as usize)
+        } else {
+            self.0.checked_sub((delta as usize).wrapping_neg())
+        };
+        addr.and_then(|a| VirtualAddress::new(a).ok())
+    }
+
+    /// Returns this address plus `rhs` bytes, wrapping around at the boundary of a `usize`
+    /// instead of saturating like `Add<usize>`.
+    ///
+    /// The wrapped result is made valid via [`VirtualAddress::new_canonical()`].
+    pub fn wrapping_add(&self, rhs: usize) -> VirtualAddress {
+        VirtualAddress::new_canonical(self.0.wrapping_add(rhs))
+    }
+
+    /// Returns this address minus `rhs` bytes, wrapping around at the boundary of a `usize`
+    /// instead of saturating like `Sub<usize>`.
+    ///
+    /// The wrapped result is made valid via [`VirtualAddress::new_canonical()`].
+    pub fn wrapping_sub(&self, rhs: usize) -> VirtualAddress {
+        VirtualAddress::new_canonical(self.0.wrapping_sub(rhs))
+    }
+
+    /// Returns this address rounded up to the nearest multiple of `multiple`,
+    /// which need not be a power of two, e.g., `192` for a descriptor stride.
+    ///
+    /// Returns `None` if `multiple` is `0`, if rounding up would overflow past `usize::MAX`,
+    /// or if the rounded value is not canonical, e.g., if it lands in the hole between the lower and higher halves.
+    pub fn round_up_to_multiple(&self, multiple: usize) -> Option<VirtualAddress> {
+        if multiple == 0 {
+            return None;
+        }
+        match self.0 % multiple {
+            0 => Some(*self),
+            rem => self.0.checked_add(multiple - rem).and_then(|a| VirtualAddress::new(a).ok()),
+        }
+    }
+
+    /// Returns this address rounded down to the nearest multiple of `multiple`,
+    /// which need not be a power of two.
+    ///
+    /// Returns `None` if `multiple` is `0`, or if the rounded value is not canonical,
+    /// e.g., if rounding down a higher-half address lands in the hole between the two halves.
+    pub fn round_down_to_multiple(&self, multiple: usize) -> Option<VirtualAddress> {
+        if multiple == 0 {
+            return None;
+        }
+        VirtualAddress::new(self.0 - self.0 % multiple).ok()
+    }
+
+    /// Returns this address rounded up to the given power-of-two `alignment`,
+    /// or `None` if rounding up would overflow past `usize::MAX`
+    /// or otherwise produce an invalid `VirtualAddress`.
+    ///
+    /// The `alignment` must be a power of two; in release builds, `None` is returned otherwise.
+    pub fn checked_align_up(&self, alignment: usize) -> Option<VirtualAddress> {
+        debug_assert!(alignment.is_power_of_two(), "VirtualAddress::checked_align_up(): alignment must be a power of two");
+        if !alignment.is_power_of_two() {
+            return None;
+        }
+        self.0.checked_add(alignment - 1)
+            .and_then(|a| VirtualAddress::new(a & !(alignment - 1)).ok())
+    }
+
+    /// Returns this address rounded up to the start of the next page, i.e., to a multiple of `PAGE_SIZE`,
+    /// or `None` if that page start would overflow past `usize::MAX` or would not be canonical,
+    /// e.g., for an address in the last page below the hole between the lower and higher halves.
+    ///
+    /// An address that is already page-aligned is returned unchanged.
+    /// This is a `const` equivalent of [`VirtualAddress::checked_align_up()`] with an alignment of `PAGE_SIZE`.
+    pub const fn align_up_to_page(&self) -> Option<VirtualAddress> {
+        match self.0.checked_add(PAGE_SIZE - 1) {
+            Some(a) => {
+                let aligned = a & !(PAGE_SIZE - 1);
+                // `new_canonical()` only changes an address that is not canonical
+                if VirtualAddress::new_canonical(aligned).0 == aligned {
+                    Some(VirtualAddress(aligned))
+                } else {
+                    None
+                }
+            }
+            None => None,
+        }
+    }
+
+    /// Returns this address rounded down to the start of the page containing it,
+    /// i.e., to a multiple of `PAGE_SIZE`.
+    pub const fn align_down_to_page(&self) -> VirtualAddress {
+        VirtualAddress::new_canonical(self.0 & !(PAGE_SIZE - 1))
+    }
+
+    /// Returns a raw `*const T` pointer to this virtual address.
+    ///
+    /// Creating the pointer is safe, but dereferencing it is only safe if this address
+    /// is currently mapped with at least read permission, is properly aligned for `T`,
+    /// and points to a valid, initialized `T` for the lifetime of the access.
+    pub const fn as_ptr<T>(&self) -> *const T {
+        self.0 as *const T
+    }
+
+    /// Returns a raw `*mut T` pointer to this virtual address.
+    ///
+    /// Dereferencing it has the same requirements as [`VirtualAddress::as_ptr()`];
+    /// in addition, writing through it requires that this address is mapped as writable
+    /// and is not aliased by any other reference for the duration of the access.
+    pub const fn as_mut_ptr<T>(&self) -> *mut T {
+        self.0 as *mut T
+    }
+
+    /// Returns the number of distinct `Page`s spanned by the addresses from this address to `other`, inclusive,
+    /// regardless of which address comes first.
+    ///
+    /// Two addresses within the same `Page` span `1` page.
+    pub fn pages_between(&self, other: VirtualAddress) -> usize {
+        let low = Page::containing_address(core::cmp::min(*self, other));
+        let high = Page::containing_address(core::cmp::max(*self, other));
+        high - low + 1
+    }
+
+    /// Returns `true` if this address lies within the given `range`.
+    ///
+    /// This is equivalent to `range.contains_virt_addr(*self)`, but reads more naturally at call sites.
+    pub fn is_in(&self, range: &PageRange) -> bool {
+        range.contains_virt_addr(*self)
+    }
 }
 impl fmt::Debug for VirtualAddress {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "v{:#X}", self.0)
     }
 }
+/// Unlike `Debug`, this honors the formatter's width, fill, and alignment,
+/// e.g., `format!("{:>20}", addr)`.
 impl fmt::Display for VirtualAddress {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+        pad_address(f, 'v', self.0)
     }
 }
 impl fmt::Pointer for VirtualAddress {
@@ -121,6 +437,12 @@ impl SubAssign<usize> for VirtualAddress {
     }
 }
 
+// SAFE: `VirtualAddress` is a `#[repr(transparent)]` wrapper around a `usize`.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for VirtualAddress {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for VirtualAddress {}
+
 impl From<VirtualAddress> for usize {
     #[inline]
     fn from(virt_addr: VirtualAddress) -> usize {
@@ -130,39 +452,77 @@ impl From<VirtualAddress> for usize {
 
 
 /// A physical memory address, which is a `usize` under the hood.
+///
+/// Like [`VirtualAddress`], the derived operators only accept another `PhysicalAddress`:
+/// ```compile_fail
+/// # extern crate memory_structs;
+/// # use memory_structs::{PhysicalAddress, VirtualAddress};
+/// let _ = PhysicalAddress::zero() - VirtualAddress::zero();
+/// ```
+/// ```
+/// # extern crate memory_structs;
+/// # use memory_structs::PhysicalAddress;
+/// let end = PhysicalAddress::new_canonical(0x3000);
+/// assert_eq!(end - PhysicalAddress::new_canonical(0x1000), PhysicalAddress::new_canonical(0x2000));
+/// ```
 #[derive(
     Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, 
     Binary, Octal, LowerHex, UpperHex, 
     BitAnd, BitOr, BitXor, BitAndAssign, BitOrAssign, BitXorAssign, 
     Add, Sub, AddAssign, SubAssign,
-    FromBytes,
+    FromBytes, AsBytes,
 )]
 #[repr(transparent)]
 pub struct PhysicalAddress(usize);
 
 impl PhysicalAddress {
+    /// The lowest representable `PhysicalAddress`, `0`.
+    pub const MIN: PhysicalAddress = PhysicalAddress(0);
+
+    /// The highest representable `PhysicalAddress`, which has all of its lower `MAX_PHYSICAL_ADDRESS_BITS` set.
+    pub const MAX: PhysicalAddress = PhysicalAddress::new_canonical(usize::MAX);
+
     /// Creates a new `PhysicalAddress`,
     /// checking that the bits (64:52] are 0.
-    pub fn new(phys_addr: usize) -> Result<PhysicalAddress, &'static str> {
-        match phys_addr.get_bits(52..64) {
+    ///
+    /// The number of usable low bits is given by `MAX_PHYSICAL_ADDRESS_BITS`;
+    /// an address with any higher bit set is rejected.
+    pub fn new(phys_addr: usize) -> Result<PhysicalAddress, MemoryStructError> {
+        match phys_addr.get_bits(MAX_PHYSICAL_ADDRESS_BITS..64) {
             0 => Ok(PhysicalAddress(phys_addr)),
-            _ => Err("PhysicalAddress bits 52-63 must be zero"),
+            _ => Err(MemoryStructError::PhysicalAddressTooLarge),
         }
     }
 
     /// Creates a new `PhysicalAddress` that is guaranteed to be canonical
     /// by forcing the upper bits (64:52] to be 0.
-    pub fn new_canonical(mut phys_addr: usize) -> PhysicalAddress {
-        phys_addr.set_bits(52..64, 0);
-        PhysicalAddress(phys_addr)
+    ///
+    /// This is the unchecked counterpart to [`PhysicalAddress::new()`].
+    pub const fn new_canonical(phys_addr: usize) -> PhysicalAddress {
+        // phys_addr.set_bits(MAX_PHYSICAL_ADDRESS_BITS..64, 0);
+
+        // The below code is semantically equivalent to the above, but it works in const functions.
+        PhysicalAddress(phys_addr & ((1 << MAX_PHYSICAL_ADDRESS_BITS) - 1))
     }
 
     /// Returns the underlying `usize` value for this `PhysicalAddress`.
     #[inline]
-    pub fn value(&self) -> usize {
+    pub const fn value(&self) -> usize {
         self.0
     }
 
+    /// Returns this address as a `NonZeroUsize`, or `None` if this is the zero address,
+    /// e.g., to store a present address compactly in a niche-optimized `Option`.
+    pub const fn as_nonzero(&self) -> Option<NonZeroUsize> {
+        NonZeroUsize::new(self.0)
+    }
+
+    /// Creates a `PhysicalAddress` from the given nonzero value,
+    /// which is made valid via [`PhysicalAddress::new_canonical()`].
+    pub const fn from_nonzero(addr: NonZeroUsize) -> PhysicalAddress {
+        PhysicalAddress::new_canonical(addr.get())
+    }
+
     /// Creates a PhysicalAddress with the value 0.
     pub const fn zero() -> PhysicalAddress {
         PhysicalAddress(0)
@@ -175,15 +535,146 @@ impl PhysicalAddress {
     pub fn frame_offset(&self) -> usize {
         self.0 & (PAGE_SIZE - 1)
     }
+
+    /// Returns the `Frame` containing this `PhysicalAddress`.
+    ///
+    /// This is equivalent to [`Frame::containing_address()`].
+    pub const fn containing_frame(&self) -> Frame {
+        Frame::containing_address(*self)
+    }
+
+    /// Returns the base address of the memory Frame containing this PhysicalAddress,
+    /// i.e., this PhysicalAddress with its `frame_offset()` bits cleared.
+    pub const fn frame_base(&self) -> PhysicalAddress {
+        PhysicalAddress(self.0 & !(PAGE_SIZE - 1))
+    }
+
+    /// Returns the offset of this PhysicalAddress into the given `FrameRange`,
+    /// or `None` if this address is not covered by that range.
+    ///
+    /// This is equivalent to [`FrameRange::offset_of_address()`].
+    pub fn offset_into(&self, range: &FrameRange) -> Option<usize> {
+        range.offset_of_address(*self)
+    }
+
+    /// Returns this address moved by the signed `delta` number of bytes,
+    /// or `None` if that would overflow past the maximum address, underflow past `0`,
+    /// or otherwise produce an invalid `PhysicalAddress`.
+    pub fn checked_offset(&self, delta: isize) -> Option<PhysicalAddress> {
+        let addr = if delta >= 0 {
+            self.0.checked_add(delta as usize)
+        } else {
+            self.0.checked_sub((delta as usize).wrapping_neg())
+        };
+        addr.and_then(|a| PhysicalAddress::new(a).ok())
+    }
+
+    /// Returns this address plus `rhs` bytes, wrapping around at the boundary of a `usize`
+    /// instead of saturating like `Add<usize>`.
+    ///
+    /// The wrapped result is made valid via [`PhysicalAddress::new_canonical()`].
+    pub fn wrapping_add(&self, rhs: usize) -> PhysicalAddress {
+        PhysicalAddress::new_canonical(self.0.wrapping_add(rhs))
+    }
+
+    /// Returns this address minus `rhs` bytes, wrapping around at the boundary of a `usize`
+    /// instead of saturating like `Sub<usize>`.
+    ///
+    /// The wrapped result is made valid via [`PhysicalAddress::new_canonical()`].
+    pub fn wrapping_sub(&self, rhs: usize) -> PhysicalAddress {
+        PhysicalAddress::new_canonical(self.0.wrapping_sub(rhs))
+    }
+
+    /// Returns this address rounded up to the nearest multiple of `multiple`,
+    /// which need not be a power of two, e.g., `192` for a descriptor stride.
+    ///
+    /// Returns `None` if `multiple` is `0`, if rounding up would overflow past `usize::MAX`,
+    /// or if the rounded value would have bits set above `MAX_PHYSICAL_ADDRESS_BITS`.
+    pub fn round_up_to_multiple(&self, multiple: usize) -> Option<PhysicalAddress> {
+        if multiple == 0 {
+            return None;
+        }
+        match self.0 % multiple {
+            0 => Some(*self),
+            rem => self.0.checked_add(multiple - rem).and_then(|a| PhysicalAddress::new(a).ok()),
+        }
+    }
+
+    /// Returns this address rounded down to the nearest multiple of `multiple`,
+    /// which need not be a power of two.
+    ///
+    /// Returns `None` only if `multiple` is `0`,
+    /// as rounding a valid `PhysicalAddress` down always yields another valid one.
+    pub fn round_down_to_multiple(&self, multiple: usize) -> Option<PhysicalAddress> {
+        if multiple == 0 {
+            return None;
+        }
+        PhysicalAddress::new(self.0 - self.0 % multiple).ok()
+    }
+
+    /// Returns this address rounded up to the given power-of-two `alignment`,
+    /// or `None` if rounding up would overflow past `usize::MAX`
+    /// or otherwise produce an invalid `PhysicalAddress`.
+    ///
+    /// The `alignment` must be a power of two; in release builds, `None` is returned otherwise.
+    pub fn checked_align_up(&self, alignment: usize) -> Option<PhysicalAddress> {
+        debug_assert!(alignment.is_power_of_two(), "PhysicalAddress::checked_align_up(): alignment must be a power of two");
+        if !alignment.is_power_of_two() {
+            return None;
+        }
+        self.0.checked_add(alignment - 1)
+            .and_then(|a| PhysicalAddress::new(a & !(alignment - 1)).ok())
+    }
+
+    /// Returns this address rounded up to the start of the next frame, i.e., to a multiple of `PAGE_SIZE`,
+    /// or `None` if that frame start would exceed the highest physical address.
+    ///
+    /// An address that is already frame-aligned is returned unchanged.
+    /// This is a `const` equivalent of [`PhysicalAddress::checked_align_up()`] with an alignment of `PAGE_SIZE`.
+    pub const fn align_up_to_frame(&self) -> Option<PhysicalAddress> {
+        // a valid physical address is far below `usize::MAX`, so this cannot overflow
+        let aligned = (self.0 + PAGE_SIZE - 1) & !(PAGE_SIZE - 1);
+        // `new_canonical()` only changes an address with bits set above `MAX_PHYSICAL_ADDRESS_BITS`
+        if PhysicalAddress::new_canonical(aligned).0 == aligned {
+            Some(PhysicalAddress(aligned))
+        } else {
+            None
+        }
+    }
+
+    /// Returns this address rounded down to the start of the frame containing it,
+    /// i.e., to a multiple of `PAGE_SIZE`.
+    pub const fn align_down_to_frame(&self) -> PhysicalAddress {
+        PhysicalAddress::new_canonical(self.0 & !(PAGE_SIZE - 1))
+    }
+
+    /// Returns the number of distinct `Frame`s spanned by the addresses from this address to `other`, inclusive,
+    /// regardless of which address comes first.
+    ///
+    /// Two addresses within the same `Frame` span `1` frame.
+    pub fn frames_between(&self, other: PhysicalAddress) -> usize {
+        let low = Frame::containing_address(core::cmp::min(*self, other));
+        let high = Frame::containing_address(core::cmp::max(*self, other));
+        high - low + 1
+    }
+
+    /// Returns `true` if this address lies within the given `range`.
+    ///
+    /// This is equivalent to `range.contains_phys_addr(*self)`, but reads more naturally at call sites.
+    pub fn is_in(&self, range: &FrameRange) -> bool {
+        range.contains_phys_addr(*self)
+    }
 }
 impl fmt::Debug for PhysicalAddress {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "p{:#X}", self.0)
     }
 }
+/// Unlike `Debug`, this honors the formatter's width, fill, and alignment,
+/// e.g., `format!("{:>20}", addr)`.
 impl fmt::Display for PhysicalAddress {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+        pad_address(f, 'p', self.0)
     }
 }
 impl fmt::Pointer for PhysicalAddress {
@@ -220,6 +711,12 @@ impl SubAssign<usize> for PhysicalAddress {
     }
 }
 
+// SAFE: `PhysicalAddress` is a `#[repr(transparent)]` wrapper around a `usize`.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for PhysicalAddress {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for PhysicalAddress {}
+
 impl From<PhysicalAddress> for usize {
     #[inline]
     fn from(virt_addr: PhysicalAddress) -> usize {
@@ -229,7 +726,14 @@ impl From<PhysicalAddress> for usize {
 
 
 /// An area of physical memory.
-#[derive(Copy, Clone, Debug, Default)]
+///
+/// This struct is `#[repr(C)]` and has no padding between its fields,
+/// so a memory map can be reinterpreted directly from a byte buffer using `zerocopy`.
+///
+/// Areas are ordered by their `base_addr`, then by their `size_in_bytes`
+/// (and lastly by `typ` and `acpi`, for consistency with equality),
+/// so an unsorted memory map can be sorted directly.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, FromBytes, AsBytes)]
 #[repr(C)]
 pub struct PhysicalMemoryArea {
     pub base_addr: PhysicalAddress,
@@ -251,12 +755,155 @@ impl PhysicalMemoryArea {
             acpi: acpi,
         }
     }
+
+    /// Returns the exclusive end address of this area, i.e., `base_addr + size_in_bytes`.
+    pub fn end_address(&self) -> PhysicalAddress {
+        self.base_addr + self.size_in_bytes
+    }
+
+    /// Returns the last `Frame` that includes part of this area,
+    /// or `None` if this area's size is `0`.
+    pub fn last_frame(&self) -> Option<Frame> {
+        if self.size_in_bytes == 0 {
+            None
+        } else {
+            Some(Frame::containing_address(self.end_address() - 1))
+        }
+    }
+
+    /// Splits this area into the parts that come before and after the given `hole`,
+    /// e.g., to carve a reserved region out of a usable area.
+    ///
+    /// Both returned parts keep the `typ` and `acpi` values of this area.
+    /// A part is `None` if it would be empty, e.g., if the `hole` touches that boundary of this area.
+    /// If the `hole` lies entirely outside of this area, this area is returned unchanged
+    /// as the part on the opposite side of the `hole`.
+    pub fn split_around(&self, hole: &FrameRange) -> (Option<PhysicalMemoryArea>, Option<PhysicalMemoryArea>) {
+        let area_start = self.base_addr.value();
+        let area_end = area_start.saturating_add(self.size_in_bytes);
+        if hole.is_empty() {
+            return (if self.size_in_bytes > 0 { Some(*self) } else { None }, None);
+        }
+        let hole_start = hole.start_address().value();
+        let hole_end = hole.0.end().start_address().value().saturating_add(PAGE_SIZE);
+
+        let before_end = core::cmp::min(hole_start, area_end);
+        let before = if before_end > area_start {
+            Some(PhysicalMemoryArea::new(self.base_addr, before_end - area_start, self.typ, self.acpi))
+        } else {
+            None
+        };
+        let after_start = core::cmp::max(hole_end, area_start);
+        let after = if area_end > after_start {
+            Some(PhysicalMemoryArea::new(PhysicalAddress::new_canonical(after_start), area_end - after_start, self.typ, self.acpi))
+        } else {
+            None
+        };
+        (before, after)
+    }
+
+    /// Returns the range of `Frame`s that covers this area's entire byte extent.
+    ///
+    /// If this area's size is `0`, an empty `FrameRange` is returned.
+    /// This is the inverse of [`FrameRange::as_physical_memory_area()`].
+    pub fn frame_range(&self) -> FrameRange {
+        if self.size_in_bytes == 0 {
+            FrameRange::empty()
+        } else {
+            FrameRange::from_phys_addr(self.base_addr, self.size_in_bytes)
+        }
+    }
+
+    /// Returns the `Frame`s in the given `range` that also cover part of this area,
+    /// or an empty `FrameRange` if they do not overlap.
+    pub fn intersect(&self, range: &FrameRange) -> FrameRange {
+        let area_frames = self.frame_range();
+        if area_frames.is_empty() || range.is_empty() {
+            return FrameRange::empty();
+        }
+        let start = core::cmp::max(*area_frames.start(), *range.start());
+        let end = core::cmp::min(*area_frames.end(), *range.end());
+        start.range_to(end)
+    }
+
+    /// Returns an iterator over the starting `PhysicalAddress` of each `Frame` covered by this area,
+    /// in ascending order, e.g., to zero out a region one page at a time.
+    ///
+    /// A zero-size area yields nothing.
+    pub fn iter_page_starts(&self) -> impl Iterator<Item = PhysicalAddress> {
+        self.frame_range().into_iter().map(|frame| frame.start_address())
+    }
+}
+
+
+/// The `typ` value of a `PhysicalMemoryArea` that is usable RAM,
+/// as defined by the multiboot2 memory map.
+pub const USABLE_MEMORY_AREA_TYPE: u32 = 1;
+
+/// A read-only view of a boot memory map, i.e., a slice of `PhysicalMemoryArea`s,
+/// which provides common queries over its usable areas without allocating.
+///
+/// An area is considered usable if its `typ` is [`USABLE_MEMORY_AREA_TYPE`] and its size is nonzero.
+#[derive(Clone, Copy, Debug)]
+pub struct MemoryMap<'a>(&'a [PhysicalMemoryArea]);
+
+impl<'a> MemoryMap<'a> {
+    /// Creates a new `MemoryMap` over the given slice of areas.
+    pub const fn new(areas: &'a [PhysicalMemoryArea]) -> MemoryMap<'a> {
+        MemoryMap(areas)
+    }
+
+    /// Returns all areas in this memory map, including non-usable ones.
+    pub fn areas(&self) -> &'a [PhysicalMemoryArea] {
+        self.0
+    }
+
+    /// Returns an iterator over the usable areas in this memory map, in their original order.
+    pub fn usable_regions(&self) -> impl Iterator<Item = &'a PhysicalMemoryArea> + 'a {
+        self.0.iter().filter(|area| area.typ == USABLE_MEMORY_AREA_TYPE && area.size_in_bytes > 0)
+    }
+
+    /// Returns the total size in bytes of all usable areas in this memory map.
+    pub fn total_usable_bytes(&self) -> usize {
+        self.usable_regions().fold(0, |total, area| total.saturating_add(area.size_in_bytes))
+    }
+
+    /// Returns the largest usable area in this memory map,
+    /// or `None` if there are no usable areas.
+    ///
+    /// If multiple usable areas share the largest size, the first one is returned.
+    pub fn largest_usable_region(&self) -> Option<&'a PhysicalMemoryArea> {
+        self.usable_regions().fold(None, |largest: Option<&'a PhysicalMemoryArea>, area| match largest {
+            Some(l) if l.size_in_bytes >= area.size_in_bytes => Some(l),
+            _ => Some(area),
+        })
+    }
+}
+
+
+/// Splits the given page or frame `number` into one index per page table level,
+/// in which each entry of `levels` is the bit width of that level's index,
+/// ordered from the highest level down to the lowest level.
+fn split_table_indices<N>(number: usize, levels: &[usize]) -> Result<heapless::Vec<usize, N>, MemoryStructError>
+    where N: ArrayLength<usize>
+{
+    const USIZE_BITS: usize = core::mem::size_of::<usize>() * 8;
+    let mut indices: heapless::Vec<usize, N> = heapless::Vec::new();
+    let mut shift: usize = levels.iter().sum();
+    for &bits in levels {
+        shift -= bits;
+        let value = if shift >= USIZE_BITS { 0 } else { number >> shift };
+        let mask = if bits >= USIZE_BITS { usize::MAX } else { (1 << bits) - 1 };
+        indices.push(value & mask).map_err(|_| MemoryStructError::CapacityExceeded)?;
+    }
+    Ok(indices)
 }
 
 
 /// A `Frame` is a chunk of **physical** memory,
 /// similar to how a `Page` is a chunk of **virtual** memory.
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, AsBytes)]
+#[repr(transparent)]
 pub struct Frame {
     pub number: usize,
 }
@@ -265,28 +912,151 @@ impl fmt::Debug for Frame {
         write!(f, "Frame(p{:#X})", self.start_address())
     }
 }
+/// Displays this `Frame` as its starting `PhysicalAddress`, e.g., `p0x1000`,
+/// which is terser than its `Debug` form.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.start_address(), f)
+    }
+}
 
 impl Frame {
     /// Returns the `Frame` containing the given `PhysicalAddress`.
-    pub fn containing_address(phys_addr: PhysicalAddress) -> Frame {
+    pub const fn containing_address(phys_addr: PhysicalAddress) -> Frame {
         Frame {
-            number: phys_addr.value() / PAGE_SIZE,
+            number: phys_addr.value() >> PAGE_SHIFT,
+        }
+    }
+
+    /// Returns the `Frame` containing the given raw physical address,
+    /// or an error if that frame would lie beyond the highest physical address,
+    /// i.e., if `phys_addr` has any bits set above `MAX_PHYSICAL_ADDRESS_BITS`.
+    ///
+    /// This takes a raw `usize` because every `PhysicalAddress` is already within that bound.
+    /// Use this instead of [`Frame::containing_address()`] when validating addresses from untrusted sources.
+    pub fn containing_address_checked(phys_addr: usize) -> Result<Frame, MemoryStructError> {
+        let number = phys_addr >> PAGE_SHIFT;
+        if number > MAX_FRAME_NUMBER {
+            return Err(MemoryStructError::FrameNumberTooLarge);
         }
+        Ok(Frame { number })
     }
 
     /// Returns the `PhysicalAddress` at the start of this `Frame`.
-    pub fn start_address(&self) -> PhysicalAddress {
+    pub const fn start_address(&self) -> PhysicalAddress {
         PhysicalAddress::new_canonical(self.number * PAGE_SIZE)
     }
+
+    /// Returns the `Frame` with the maximum frame number (`MAX_FRAME_NUMBER`),
+    /// i.e., the `Frame` containing the highest physical address,
+    /// e.g., as an initial value when tracking the lowest frame seen so far.
+    pub const fn max() -> Frame {
+        Frame { number: MAX_FRAME_NUMBER }
+    }
+
+    /// Returns `true` if this `Frame`'s number is a multiple of the given number of `pages`,
+    /// e.g., `512` to check whether it begins a 2MiB huge frame.
+    ///
+    /// If `pages` is `0`, this returns `false`.
+    pub fn is_aligned_to(&self, pages: usize) -> bool {
+        pages != 0 && self.number % pages == 0
+    }
+
+    /// Returns the `Frame` that is `rhs` frames after this one,
+    /// or `None` if that would exceed the maximum frame number (`MAX_FRAME_NUMBER`).
+    ///
+    /// Unlike `Add<usize>`, this does not clamp the result to the maximum frame.
+    pub fn checked_add(self, rhs: usize) -> Option<Frame> {
+        match self.number.checked_add(rhs) {
+            Some(number) if number <= MAX_FRAME_NUMBER => Some(Frame { number }),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Frame` that is `rhs` frames after this one,
+    /// panicking in debug builds if that would exceed the maximum frame number (`MAX_FRAME_NUMBER`).
+    ///
+    /// In release builds, the frame number instead wraps around to `0` past `MAX_FRAME_NUMBER`.
+    /// Unlike `Add<usize>`, this never silently clamps the result.
+    pub fn strict_add(self, rhs: usize) -> Frame {
+        debug_assert!(
+            self.checked_add(rhs).is_some(),
+            "Frame::strict_add(): frame number overflowed MAX_FRAME_NUMBER"
+        );
+        Frame { number: self.number.wrapping_add(rhs) & MAX_FRAME_NUMBER }
+    }
+
+    /// Returns the `Frame` that is `delta` frames away from this one, moving forward if `delta` is positive
+    /// or backward if it is negative, e.g., for relative walks in either direction.
+    ///
+    /// Like `Add<usize>` and `Sub<usize>`, this saturates at frame `0` and at the maximum frame number (`MAX_FRAME_NUMBER`).
+    pub fn saturating_offset(self, delta: isize) -> Frame {
+        if delta >= 0 {
+            self + delta as usize
+        } else {
+            self - (delta as usize).wrapping_neg()
+        }
+    }
+
+    /// Returns the `Frame` that is `rhs` frames before this one,
+    /// or `None` if that would go below frame number `0`.
+    pub fn checked_sub(self, rhs: usize) -> Option<Frame> {
+        self.number.checked_sub(rhs).map(|number| Frame { number })
+    }
+
+    /// Returns a `FrameRange` spanning from this `Frame` to the given `end` `Frame`, both inclusive.
+    ///
+    /// If `end` comes before this `Frame`, an empty `FrameRange` is returned
+    /// rather than a malformed, inverted one.
+    pub fn range_to(self, end: Frame) -> FrameRange {
+        if end < self {
+            FrameRange::empty()
+        } else {
+            FrameRange::new(self, end)
+        }
+    }
+
+    /// Returns the `Page` with the same number as this `Frame`.
+    ///
+    /// This is only meaningful for identity-mapped memory, in which each `Page` is mapped
+    /// to the `Frame` at the same address; otherwise, the two are unrelated.
+    pub const fn to_identity_page(&self) -> Page {
+        Page { number: self.number, size: PhantomData }
+    }
+
+    /// Returns an iterator over `count` frames that are each `step` frames apart, starting from this `Frame`.
+    ///
+    /// The iterator ends early if the next frame would exceed the maximum frame number (`MAX_FRAME_NUMBER`).
+    /// The `step` must be greater than `0`.
+    pub fn step_by_frames(&self, count: usize, step: usize) -> impl Iterator<Item = Frame> {
+        debug_assert!(step > 0, "Frame::step_by_frames(): step must be greater than 0");
+        core::iter::successors(Some(*self), move |prev| prev.checked_add(step)).take(count)
+    }
+
+    /// Splits this `Frame`'s number into one index per page table level, e.g., for walking multi-level page tables.
+    ///
+    /// Each entry of `levels` is the bit width of that level's index, ordered from the highest level down to the lowest;
+    /// the returned indices follow the same order.
+    /// For example, `levels` of `[9, 9, 9, 9]` yields the P4, P3, P2, and P1 indices on x86_64.
+    ///
+    /// An error is returned if there are more levels than the capacity `N` allows.
+    pub fn table_indices<N>(&self, levels: &[usize]) -> Result<heapless::Vec<usize, N>, MemoryStructError>
+        where N: ArrayLength<usize>
+    {
+        split_table_indices(self.number, levels)
+    }
 }
 
+/// Adding a number of frames to a `Frame` saturates at the maximum frame number (`MAX_FRAME_NUMBER`).
+///
+/// Use [`Frame::checked_add()`] to detect that case, or [`Frame::strict_add()`] to panic in debug builds.
 impl Add<usize> for Frame {
     type Output = Frame;
 
     fn add(self, rhs: usize) -> Frame {
-        // cannot exceed max page number (which is also max frame number)
+        // cannot exceed max frame number
         Frame {
-            number: core::cmp::min(MAX_PAGE_NUMBER, self.number.saturating_add(rhs)),
+            number: core::cmp::min(MAX_FRAME_NUMBER, self.number.saturating_add(rhs)),
         }
     }
 }
@@ -294,7 +1064,7 @@ impl Add<usize> for Frame {
 impl AddAssign<usize> for Frame {
     fn add_assign(&mut self, rhs: usize) {
         *self = Frame {
-            number: core::cmp::min(MAX_PAGE_NUMBER, self.number.saturating_add(rhs)),
+            number: core::cmp::min(MAX_FRAME_NUMBER, self.number.saturating_add(rhs)),
         };
     }
 }
@@ -317,6 +1087,34 @@ impl SubAssign<usize> for Frame {
     }
 }
 
+/// Subtracting one `Frame` from another yields the number of frames between them,
+/// saturating at `0` if `rhs` comes after `self`.
+///
+/// Note that this differs from `Frame - usize`, which yields a new `Frame`
+/// that has been moved backwards by the given number of frames.
+impl Sub<Frame> for Frame {
+    type Output = usize;
+
+    fn sub(self, rhs: Frame) -> usize {
+        self.number.saturating_sub(rhs.number)
+    }
+}
+
+/// Compares this `Frame`'s number with a raw frame number, e.g., `frame == 5` or `frame < 100`.
+///
+/// Only this direction is implemented: a `usize == Frame` impl would apply to every `usize` comparison
+/// in crates using this one, breaking type inference for expressions like `n == x.into()`.
+impl PartialEq<usize> for Frame {
+    fn eq(&self, other: &usize) -> bool {
+        self.number == *other
+    }
+}
+impl PartialOrd<usize> for Frame {
+    fn partial_cmp(&self, other: &usize) -> Option<core::cmp::Ordering> {
+        self.number.partial_cmp(other)
+    }
+}
+
 // Implementing these functions allow `Frame` to be in an `Iterator`.
 unsafe impl Step for Frame {
     #[inline]
@@ -341,12 +1139,24 @@ pub struct FrameRange(RangeInclusive<Frame>);
 impl FrameRange {
     /// Creates a new range of `Frame`s that spans from `start` to `end`,
     /// both inclusive bounds.
-    pub fn new(start: Frame, end: Frame) -> FrameRange {
+    pub const fn new(start: Frame, end: Frame) -> FrameRange {
         FrameRange(RangeInclusive::new(start, end))
     }
 
+    /// Creates a new range of `Frame`s like [`FrameRange::new()`],
+    /// but returns an error if either bound's frame number exceeds `MAX_FRAME_NUMBER`.
+    ///
+    /// Because `Frame`'s `number` field is public, an invalid `Frame` can be constructed directly,
+    /// which would otherwise make `size_in_frames()` and iteration over the range misbehave.
+    pub fn try_new(start: Frame, end: Frame) -> Result<FrameRange, MemoryStructError> {
+        if start.number > MAX_FRAME_NUMBER || end.number > MAX_FRAME_NUMBER {
+            return Err(MemoryStructError::FrameNumberTooLarge);
+        }
+        Ok(FrameRange::new(start, end))
+    }
+
     /// Creates a FrameRange that will always yield `None`.
-    pub fn empty() -> FrameRange {
+    pub const fn empty() -> FrameRange {
         FrameRange::new(Frame { number: 1 }, Frame { number: 0 })
     }
 
@@ -361,29 +1171,119 @@ impl FrameRange {
         FrameRange::new(start_frame, end_frame)
     }
 
+    /// A convenience method for creating a new `FrameRange`
+    /// that spans all `Frame`s covering the given inclusive range of physical addresses.
+    ///
+    /// If the range's end address comes before its start address, an empty `FrameRange` is returned.
+    pub fn from_phys_addr_range(range: RangeInclusive<PhysicalAddress>) -> FrameRange {
+        let (start, end) = range.into_inner();
+        if end < start {
+            return FrameRange::empty();
+        }
+        FrameRange::new(Frame::containing_address(start), Frame::containing_address(end))
+    }
+
+    /// Creates a `FrameRange` from the given sequence of `Frame`s,
+    /// which must be contiguous, i.e., each one must come exactly one frame after the previous one.
+    ///
+    /// Returns an error if the sequence is not contiguous.
+    /// An empty sequence produces an empty `FrameRange`.
+    pub fn try_from_frames<I: IntoIterator<Item = Frame>>(frames: I) -> Result<FrameRange, MemoryStructError> {
+        let mut iter = frames.into_iter();
+        let start = match iter.next() {
+            Some(start) => start,
+            None => return Ok(FrameRange::empty()),
+        };
+        let mut end = start;
+        for next in iter {
+            if end.checked_add(1) != Some(next) {
+                return Err(MemoryStructError::NotContiguous);
+            }
+            end = next;
+        }
+        Ok(FrameRange::new(start, end))
+    }
+
     /// Returns the `PhysicalAddress` of the starting `Frame` in this `FrameRange`.
-    pub fn start_address(&self) -> PhysicalAddress {
+    pub const fn start_address(&self) -> PhysicalAddress {
         self.0.start().start_address()
     }
 
     /// Returns the number of `Frame`s covered by this iterator.
     /// Use this instead of the Iterator trait's `count()` method.
     /// This is instant, because it doesn't need to iterate over each entry, unlike normal iterators.
-    pub fn size_in_frames(&self) -> usize {
+    pub const fn size_in_frames(&self) -> usize {
         // add 1 because it's an inclusive range
         self.0.end().number + 1 - self.0.start().number
     }
 
-    /// Whether this `FrameRange` contains the given `PhysicalAddress`.
-    pub fn contains_phys_addr(&self, phys_addr: PhysicalAddress) -> bool {
-        self.0.contains(&Frame::containing_address(phys_addr))
+    /// Returns the number of `Frame`s in this range; an alias for [`FrameRange::size_in_frames()`].
+    pub const fn len(&self) -> usize {
+        self.size_in_frames()
     }
 
-    /// Returns the offset of the given `PhysicalAddress` within this `FrameRange`,
-    /// i.e., the difference between `phys_addr` and `self.start()`.
-    pub fn offset_from_start(&self, phys_addr: PhysicalAddress) -> Option<usize> {
-        if self.contains_phys_addr(phys_addr) {
-            Some(phys_addr.value() - self.start_address().value())
+    /// Returns the size in number of bytes.
+    ///
+    /// This saturates at `usize::MAX` rather than overflowing
+    /// if the range was built from invalid `Frame`s beyond the maximum frame number.
+    pub const fn size_in_bytes(&self) -> usize {
+        self.size_in_frames().saturating_mul(PAGE_SIZE)
+    }
+
+    /// Returns `true` if this `FrameRange` contains no `Frame`s,
+    /// i.e., if its starting `Frame` comes after its ending `Frame`.
+    ///
+    /// A `FrameRange` created by [`FrameRange::empty()`] always satisfies this.
+    pub const fn is_empty(&self) -> bool {
+        self.0.start().number > self.0.end().number
+    }
+
+    /// Whether this `FrameRange` contains the given `PhysicalAddress`.
+    pub fn contains_phys_addr(&self, phys_addr: PhysicalAddress) -> bool {
+        self.0.contains(&Frame::containing_address(phys_addr))
+    }
+
+    /// Whether this `FrameRange` contains the given `Frame`.
+    ///
+    /// An empty `FrameRange` contains no `Frame`s.
+    pub fn contains_frame(&self, frame: Frame) -> bool {
+        self.0.contains(&frame)
+    }
+
+    /// Returns the offset of the given `PhysicalAddress` within this `FrameRange`,
+    /// i.e., the difference between `phys_addr` and `self.start_address()`.
+    /// If the given `PhysicalAddress` is not covered by this range of `Frame`s, this returns `None`.
+    ///
+    /// # Examples
+    /// If the frame range covered addresses `0x2000` to `0x4000`, then calling
+    /// `offset_of_address(0x3500)` would return `Some(0x1500)`.
+    pub fn offset_of_address(&self, phys_addr: PhysicalAddress) -> Option<usize> {
+        if self.contains_phys_addr(phys_addr) {
+            Some(phys_addr.value() - self.start_address().value())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the offset of the given `PhysicalAddress` within this `FrameRange`,
+    /// i.e., the difference between `phys_addr` and `self.start_address()`.
+    #[deprecated(note = "use `FrameRange::offset_of_address()` instead")]
+    pub fn offset_from_start(&self, phys_addr: PhysicalAddress) -> Option<usize> {
+        self.offset_of_address(phys_addr)
+    }
+
+    /// Returns the `PhysicalAddress` at the given `offset` into this range of `Frame`s.
+    /// If the given `offset` is not covered by this range of `Frame`s, this returns `None`.
+    ///
+    /// This is the inverse of [`FrameRange::offset_of_address()`],
+    /// so the offset must be strictly less than the size of this range in bytes.
+    ///
+    /// # Examples
+    /// If the frame range covered addresses `0x2000` to `0x4000`,
+    /// then calling `address_at_offset(0x1500)` would return `Some(0x3500)`.
+    pub fn address_at_offset(&self, offset: usize) -> Option<PhysicalAddress> {
+        if !self.is_empty() && offset < self.size_in_bytes() {
+            Some(self.start_address() + offset)
         } else {
             None
         }
@@ -400,12 +1300,481 @@ impl FrameRange {
         let end = core::cmp::max(self.0.end(), &frame_to_include);
         FrameRange::new(start.clone(), end.clone())
     }
+
+    /// Returns a new, separate `FrameRange` that is extended to include the `Frame`
+    /// containing the given `PhysicalAddress`.
+    pub fn to_extended_address(&self, phys_addr: PhysicalAddress) -> FrameRange {
+        self.to_extended(Frame::containing_address(phys_addr))
+    }
+
+    /// Splits this `FrameRange` into two at the `Frame` containing the given `addr`,
+    /// returning the `Frame`s before that `Frame` and the `Frame`s from that `Frame` (inclusive) onwards.
+    ///
+    /// If `addr` is not frame-aligned, the split is rounded down to the start of the `Frame` containing it,
+    /// so that `addr` always falls within the second part.
+    /// A part is `None` if it would contain no `Frame`s, e.g., if `addr` is outside of this range.
+    pub fn split_at_address(self, addr: PhysicalAddress) -> (Option<FrameRange>, Option<FrameRange>) {
+        if self.is_empty() {
+            return (None, None);
+        }
+        let at = Frame::containing_address(addr);
+        let (start, end) = (*self.0.start(), *self.0.end());
+        if at <= start {
+            (None, Some(self))
+        } else if at > end {
+            (Some(self), None)
+        } else {
+            (Some(FrameRange::new(start, at - 1)), Some(FrameRange::new(at, end)))
+        }
+    }
+
+    /// Returns a new `FrameRange` whose end is moved forward by `frames` frames, keeping the same start.
+    ///
+    /// The end saturates at the maximum frame number (`MAX_FRAME_NUMBER`).
+    /// An empty range has no bounds to extend, so it remains empty.
+    pub fn grow_end_by(self, frames: usize) -> FrameRange {
+        if self.is_empty() {
+            return self;
+        }
+        FrameRange::new(*self.0.start(), *self.0.end() + frames)
+    }
+
+    /// Returns a new `FrameRange` whose start is moved backward by `frames` frames, keeping the same end.
+    ///
+    /// The start saturates at frame `0`.
+    /// An empty range has no bounds to extend, so it remains empty.
+    pub fn grow_start_by(self, frames: usize) -> FrameRange {
+        if self.is_empty() {
+            return self;
+        }
+        FrameRange::new(*self.0.start() - frames, *self.0.end())
+    }
+
+    /// Returns the parts of this `FrameRange` that are not covered by any of the given `holes`,
+    /// e.g., to build a free list from a region of memory minus its reserved ranges.
+    ///
+    /// The `holes` must be sorted in ascending order and must not overlap each other;
+    /// empty holes and holes outside of this range are ignored.
+    /// The leftover parts are returned in ascending order.
+    /// An error is returned if there are more leftover parts than the capacity `N` allows.
+    pub fn subtract_all<N>(&self, holes: &[FrameRange]) -> Result<heapless::Vec<FrameRange, N>, MemoryStructError>
+        where N: ArrayLength<FrameRange>
+    {
+        let mut leftovers: heapless::Vec<FrameRange, N> = heapless::Vec::new();
+        if self.is_empty() {
+            return Ok(leftovers);
+        }
+        let end = *self.0.end();
+        // the first frame that has not yet been covered by a hole or a leftover part
+        let mut next = Some(*self.0.start());
+        for hole in holes.iter().filter(|hole| !hole.is_empty()) {
+            let cursor = match next {
+                Some(cursor) if cursor <= end => cursor,
+                _ => break,
+            };
+            if *hole.0.end() < cursor {
+                continue;
+            }
+            if *hole.0.start() > end {
+                break;
+            }
+            if *hole.0.start() > cursor {
+                leftovers.push(FrameRange::new(cursor, *hole.0.start() - 1))
+                    .map_err(|_| MemoryStructError::CapacityExceeded)?;
+            }
+            next = hole.0.end().checked_add(1);
+        }
+        if let Some(cursor) = next {
+            if cursor <= end {
+                leftovers.push(FrameRange::new(cursor, end))
+                    .map_err(|_| MemoryStructError::CapacityExceeded)?;
+            }
+        }
+        Ok(leftovers)
+    }
+
+    /// Splits this `FrameRange` into `n` contiguous sub-ranges whose sizes differ by at most one `Frame`.
+    ///
+    /// See [`PageRange::split_into()`] for more details.
+    pub fn split_into(&self, n: usize) -> impl Iterator<Item = FrameRange> {
+        let start = *self.0.start();
+        let total = if self.is_empty() { 0 } else { self.size_in_frames() };
+        let (base, extra) = if n == 0 { (0, 0) } else { (total / n, total % n) };
+        (0..n).map(move |i| {
+            let size = base + if i < extra { 1 } else { 0 };
+            let offset = i * base + core::cmp::min(i, extra);
+            if size == 0 {
+                FrameRange::empty()
+            } else {
+                FrameRange::new(start + offset, start + (offset + size - 1))
+            }
+        })
+    }
+
+    /// Returns the number of `Frame`s in this `FrameRange` that come at or after the given `Frame`,
+    /// i.e., the number of frames remaining from `frame` (inclusive) to the end of this range.
+    ///
+    /// If `frame` comes before the start of this range, this returns the size of the whole range.
+    /// If `frame` comes after the end of this range, this returns `0`.
+    pub fn frames_from(&self, frame: Frame) -> usize {
+        if self.is_empty() || frame > *self.0.end() {
+            0
+        } else if frame < *self.0.start() {
+            self.size_in_frames()
+        } else {
+            self.0.end().number + 1 - frame.number
+        }
+    }
+
+    /// Returns the `Frame` at the given `index` within this range, i.e., `start + index`,
+    /// or `None` if that would be beyond the inclusive end of this range.
+    pub fn nth_frame(&self, index: usize) -> Option<Frame> {
+        if index < self.size_in_frames() {
+            Some(*self.0.start() + index)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `Frame` at the given `index` within this range, like indexing a slice.
+    ///
+    /// This is the panicking counterpart of [`FrameRange::nth_frame()`].
+    /// `FrameRange` cannot implement `Index<usize>`, because that must return a reference,
+    /// but the `Frame`s in a range are computed rather than stored.
+    ///
+    /// # Panics
+    /// Panics if `index` is beyond the end of this range.
+    pub fn frame_at(&self, index: usize) -> Frame {
+        match self.nth_frame(index) {
+            Some(frame) => frame,
+            None => panic!("FrameRange::frame_at(): index {} out of bounds for range of {} frames", index, self.size_in_frames()),
+        }
+    }
+
+    /// Returns the number of `Frame`s that this `FrameRange` shares with the given `other` range,
+    /// or `0` if they are disjoint.
+    ///
+    /// This is cheaper than constructing the intersection of the two ranges just to count its size.
+    pub fn overlap_size(&self, other: &FrameRange) -> usize {
+        if self.is_empty() || other.is_empty() {
+            return 0;
+        }
+        let start = core::cmp::max(self.0.start().number, other.0.start().number);
+        let end = core::cmp::min(self.0.end().number, other.0.end().number);
+        if start > end { 0 } else { end - start + 1 }
+    }
+
+    /// Returns `true` if every `Frame` in the given slice lies within this range.
+    ///
+    /// This stops at the first `Frame` outside of this range. An empty slice always returns `true`.
+    pub fn contains_all(&self, frames: &[Frame]) -> bool {
+        frames.iter().all(|frame| self.0.contains(frame))
+    }
+
+    /// Returns the smallest `FrameRange` that encloses both this range and the `other` range,
+    /// including any gap between them.
+    ///
+    /// Empty ranges are ignored: if only one of the two ranges is empty, the other is returned,
+    /// and if both are empty, an empty `FrameRange` is returned.
+    pub fn bounding(&self, other: &FrameRange) -> FrameRange {
+        match (self.is_empty(), other.is_empty()) {
+            (true, true) => FrameRange::empty(),
+            (true, false) => other.clone(),
+            (false, true) => self.clone(),
+            (false, false) => FrameRange::new(
+                core::cmp::min(*self.0.start(), *other.0.start()),
+                core::cmp::max(*self.0.end(), *other.0.end()),
+            ),
+        }
+    }
+
+    /// Returns the number of `Frame`s strictly between this range and the `other` range,
+    /// regardless of which one comes first.
+    ///
+    /// Adjacent ranges, in which one ends right before the other begins, have a gap of `Some(0)`.
+    /// Returns `None` if the two ranges overlap or if either range is empty.
+    pub fn gap_to(&self, other: &FrameRange) -> Option<usize> {
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+        let (low, high) = if self.0.start() <= other.0.start() { (self, other) } else { (other, self) };
+        if low.0.end() >= high.0.start() {
+            None
+        } else {
+            Some(high.0.start().number - low.0.end().number - 1)
+        }
+    }
+
+    /// Returns `true` if this range and the `other` range are exactly adjacent,
+    /// i.e., one ends right before the other begins, with no gap and no overlap.
+    ///
+    /// Overlapping ranges can also be merged, but are not adjacent. Empty ranges are never adjacent.
+    pub fn is_adjacent_to(&self, other: &FrameRange) -> bool {
+        self.gap_to(other) == Some(0)
+    }
+
+    /// Returns an iterator that pairs each `Frame` in this range with the corresponding `Page`
+    /// in the given `pages` range, in ascending order, e.g., to map a contiguous physical region.
+    ///
+    /// The iterator stops at the end of whichever range is shorter.
+    pub fn zip_pages(&self, pages: &PageRange) -> impl Iterator<Item = (Page, Frame)> {
+        pages.clone().into_iter().zip(self.clone())
+    }
+
+    /// Returns a new `FrameRange` with both bounds moved forward by `offset` bytes,
+    /// i.e., by `offset / PAGE_SIZE` frames.
+    ///
+    /// Returns an error if `offset` is not a multiple of `PAGE_SIZE`,
+    /// or if the shifted range would extend beyond the maximum frame number.
+    /// An empty range remains empty.
+    pub fn shifted_by_bytes(&self, offset: usize) -> Result<FrameRange, MemoryStructError> {
+        if offset % PAGE_SIZE != 0 {
+            return Err(MemoryStructError::NotPageAligned);
+        }
+        if self.is_empty() {
+            return Ok(FrameRange::empty());
+        }
+        let count = offset / PAGE_SIZE;
+        match (self.0.start().checked_add(count), self.0.end().checked_add(count)) {
+            (Some(start), Some(end)) => Ok(FrameRange::new(start, end)),
+            _ => Err(MemoryStructError::AddressOverflow),
+        }
+    }
+
+    /// Returns an iterator that greedily carves this `FrameRange` into aligned blocks,
+    /// yielding each block's `FrameRange` paired with the block size (in number of frames) used for it.
+    ///
+    /// The given `block_frames` should be sorted in descending order, e.g., `[512, 1]`
+    /// for 2MiB huge frames and 4KiB regular frames.
+    /// At each step, the largest block size is chosen whose alignment matches the current frame
+    /// and that fits within the remainder of this range.
+    /// If no given block size fits, a single frame is yielded with a block size of `1`.
+    pub fn iter_aligned_blocks<'a>(&self, block_frames: &'a [usize]) -> impl Iterator<Item = (FrameRange, usize)> + 'a {
+        let mut next = if self.is_empty() { None } else { Some(*self.0.start()) };
+        let end = *self.0.end();
+        core::iter::from_fn(move || {
+            let current = next?;
+            let remaining = end.number - current.number + 1;
+            let block = block_frames.iter()
+                .cloned()
+                .find(|&b| b != 0 && b <= remaining && current.is_aligned_to(b))
+                .unwrap_or(1);
+            let block_end = current + (block - 1);
+            next = if block_end < end { Some(block_end + 1) } else { None };
+            Some((FrameRange::new(current, block_end), block))
+        })
+    }
+
+    /// Returns the largest sub-range of this `FrameRange` that consists only of whole blocks of `frames` frames,
+    /// i.e., that starts at a multiple of `frames` and ends right before a multiple of `frames`,
+    /// e.g., `512` to find the part of this range that can be covered by 2MiB huge frames.
+    ///
+    /// The unaligned edges of this range are trimmed off.
+    /// Returns an empty `FrameRange` if no whole block fits within this range or if `frames` is `0`.
+    pub fn aligned_subrange(&self, frames: usize) -> FrameRange {
+        if frames == 0 || self.is_empty() {
+            return FrameRange::empty();
+        }
+        let start = match self.0.start().number.checked_add(frames - 1) {
+            Some(n) => n / frames * frames,
+            None => return FrameRange::empty(),
+        };
+        // the exclusive end, rounded down to a multiple of `frames`
+        let end = (self.0.end().number + 1) / frames * frames;
+        if end <= start {
+            FrameRange::empty()
+        } else {
+            FrameRange::new(Frame { number: start }, Frame { number: end - 1 })
+        }
+    }
+
+    /// Returns the smallest `FrameRange` that encloses this range and consists only of whole blocks of `frames` frames,
+    /// i.e., the start is rounded down and the end is rounded up to the block boundaries,
+    /// e.g., `512` to find the 2MiB huge frame region(s) that this range falls within.
+    ///
+    /// This is the outer counterpart of [`FrameRange::aligned_subrange()`].
+    /// The end is clamped to the maximum frame number (`MAX_FRAME_NUMBER`).
+    /// If `frames` is `0`, this range is returned unchanged, and an empty range remains empty.
+    pub fn enclosing_aligned(&self, frames: usize) -> FrameRange {
+        if frames == 0 || self.is_empty() {
+            return self.clone();
+        }
+        let start = self.0.start().number / frames * frames;
+        // the last frame in the block containing this range's end
+        let end = (self.0.end().number / frames * frames).saturating_add(frames - 1);
+        FrameRange::new(
+            Frame { number: start },
+            Frame { number: core::cmp::min(end, MAX_FRAME_NUMBER) },
+        )
+    }
+
+    /// Coalesces the given `FrameRange`s into the minimal set of maximal contiguous ranges,
+    /// in which each overlapping or adjacent pair of ranges has been merged into one.
+    ///
+    /// The returned ranges are sorted by their starting `Frame`, and empty input ranges are ignored.
+    /// The capacity `N` need only be large enough to hold the coalesced output ranges;
+    /// an error is returned if there are more disjoint ranges than the capacity allows.
+    pub fn coalesce<N, I>(ranges: I) -> Result<heapless::Vec<FrameRange, N>, MemoryStructError>
+        where N: ArrayLength<FrameRange>,
+              I: IntoIterator<Item = FrameRange>,
+    {
+        let mut coalesced: heapless::Vec<FrameRange, N> = heapless::Vec::new();
+        for mut range in ranges.into_iter().filter(|r| !r.is_empty()) {
+            // Absorb every existing range that overlaps or is adjacent to the new one,
+            // such that the new range is disjoint from all others when it's added.
+            while let Some(idx) = coalesced.iter().position(|existing| {
+                existing.0.start().number <= range.0.end().number.saturating_add(1)
+                    && range.0.start().number <= existing.0.end().number.saturating_add(1)
+            }) {
+                let existing = coalesced.swap_remove(idx);
+                range = FrameRange::new(
+                    core::cmp::min(*existing.0.start(), *range.0.start()),
+                    core::cmp::max(*existing.0.end(), *range.0.end()),
+                );
+            }
+            coalesced.push(range).map_err(|_| MemoryStructError::CapacityExceeded)?;
+        }
+        coalesced.sort_unstable();
+        Ok(coalesced)
+    }
+
+    /// Splits this `FrameRange` along the areas of the given memory `map`,
+    /// returning each non-empty sub-range tagged with the `typ` of the area that covers it,
+    /// e.g., to find the reserved parts of an otherwise usable span.
+    ///
+    /// The `map` should be sorted by base address, in which case the returned sub-ranges are also sorted.
+    /// Frames that are not covered by any area are omitted.
+    /// A frame that is only partially covered by an area is included in that area's sub-range,
+    /// so areas whose bounds are not frame-aligned may produce sub-ranges that share a boundary frame.
+    /// An error is returned if there are more sub-ranges than the capacity `N` allows.
+    pub fn partition_by_map<N>(&self, map: &[PhysicalMemoryArea]) -> Result<heapless::Vec<(FrameRange, u32), N>, MemoryStructError>
+        where N: ArrayLength<(FrameRange, u32)>
+    {
+        let mut parts: heapless::Vec<(FrameRange, u32), N> = heapless::Vec::new();
+        for area in map {
+            let shared = area.intersect(self);
+            if !shared.is_empty() {
+                parts.push((shared, area.typ)).map_err(|_| MemoryStructError::CapacityExceeded)?;
+            }
+        }
+        Ok(parts)
+    }
+
+    /// Consumes this `FrameRange` and returns an iterator over its `Frame`s in reverse order,
+    /// i.e., from the ending `Frame` down to the starting `Frame`, both inclusive.
+    ///
+    /// The returned iterator is itself double-ended, so it can also be walked forwards.
+    pub fn rev(self) -> impl DoubleEndedIterator<Item = Frame> {
+        self.0.rev()
+    }
+
+    /// Returns an iterator over the starting `PhysicalAddress` of each `Frame` in this range,
+    /// in descending order from the last `Frame` down to the first.
+    pub fn iter_addresses_rev(&self) -> impl Iterator<Item = PhysicalAddress> {
+        self.clone().rev().map(|frame| frame.start_address())
+    }
+
+    /// Returns the remainder of this `FrameRange` after removing its first `frames` frames.
+    ///
+    /// If `frames` is greater than or equal to the size of this range, an empty `FrameRange` is returned.
+    pub fn trim_start(self, frames: usize) -> FrameRange {
+        if self.is_empty() || frames >= self.size_in_frames() {
+            FrameRange::empty()
+        } else {
+            FrameRange::new(*self.0.start() + frames, *self.0.end())
+        }
+    }
+
+    /// Returns the remainder of this `FrameRange` after removing its last `frames` frames.
+    ///
+    /// If `frames` is greater than or equal to the size of this range, an empty `FrameRange` is returned.
+    pub fn trim_end(self, frames: usize) -> FrameRange {
+        if self.is_empty() || frames >= self.size_in_frames() {
+            FrameRange::empty()
+        } else {
+            FrameRange::new(*self.0.start(), *self.0.end() - frames)
+        }
+    }
+
+    /// Returns the prefix of this `FrameRange` that covers at most `max_bytes` bytes,
+    /// rounded down to a whole number of frames.
+    ///
+    /// If `max_bytes` is at least the size of this range, this range is returned unchanged.
+    /// If `max_bytes` is less than one frame, an empty `FrameRange` is returned.
+    pub fn truncate_to_bytes(self, max_bytes: usize) -> FrameRange {
+        let frames = max_bytes / PAGE_SIZE;
+        if self.is_empty() || frames == 0 {
+            FrameRange::empty()
+        } else if frames >= self.size_in_frames() {
+            self
+        } else {
+            FrameRange::new(*self.0.start(), *self.0.start() + (frames - 1))
+        }
+    }
+
+    /// Returns an iterator over each pair of consecutive `Frame`s in this range, i.e., `(frame, frame + 1)`,
+    /// similar to `slice::windows(2)`.
+    ///
+    /// An empty or single-frame range yields no pairs.
+    pub fn windows(&self) -> impl Iterator<Item = (Frame, Frame)> {
+        // every frame except the last one begins a pair
+        self.clone().trim_end(1).into_iter().map(|frame| (frame, frame + 1))
+    }
+
+    /// Returns an iterator over the `Frame`s in this range whose number is a multiple of `stride`,
+    /// e.g., to visit only the frames of one cache color.
+    ///
+    /// The `stride` must be greater than `0`.
+    pub fn filter_aligned(&self, stride: usize) -> impl Iterator<Item = Frame> {
+        debug_assert!(stride > 0, "FrameRange::filter_aligned(): stride must be greater than 0");
+        self.clone().into_iter().filter(move |frame| frame.is_aligned_to(stride))
+    }
+
+    /// Returns a `PhysicalMemoryArea` with the given `typ` and `acpi` values
+    /// that covers the entire byte extent of this `FrameRange`.
+    ///
+    /// If this range is empty, the returned area starts at address `0` and has a size of `0`.
+    /// This is the inverse of [`PhysicalMemoryArea::frame_range()`].
+    pub fn as_physical_memory_area(&self, typ: u32, acpi: u32) -> PhysicalMemoryArea {
+        if self.is_empty() {
+            PhysicalMemoryArea::new(PhysicalAddress::zero(), 0, typ, acpi)
+        } else {
+            PhysicalMemoryArea::new(self.start_address(), self.size_in_bytes(), typ, acpi)
+        }
+    }
+
+    /// Consumes this `FrameRange` and returns a `CountedFrameRange`
+    /// that caches its number of frames, for consumers that query it repeatedly.
+    pub fn with_cached_len(self) -> CountedFrameRange {
+        let len = if self.is_empty() { 0 } else { self.size_in_frames() };
+        CountedFrameRange { range: self, len }
+    }
 }
 impl fmt::Debug for FrameRange {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		write!(f, "{:?}", self.0)
 	}
 }
+impl PartialOrd for FrameRange {
+    fn partial_cmp(&self, other: &FrameRange) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for FrameRange {
+    /// Orders `FrameRange`s by their starting `Frame`, then by their ending `Frame`.
+    fn cmp(&self, other: &FrameRange) -> core::cmp::Ordering {
+        self.0.start().cmp(other.0.start())
+            .then_with(|| self.0.end().cmp(other.0.end()))
+            // an exhausted range iterator is not equal to a fresh one, so this keeps `Ord` consistent with `Eq`
+            .then_with(|| RangeInclusive::is_empty(&self.0).cmp(&RangeInclusive::is_empty(&other.0)))
+    }
+}
+impl Default for FrameRange {
+    /// Returns an empty `FrameRange`, the same as `FrameRange::empty()`.
+    fn default() -> FrameRange {
+        FrameRange::empty()
+    }
+}
 impl Deref for FrameRange {
     type Target = RangeInclusive<Frame>;
     fn deref(&self) -> &RangeInclusive<Frame> {
@@ -417,6 +1786,53 @@ impl DerefMut for FrameRange {
         &mut self.0
     }
 }
+/// The preferred way to explicitly access the inner `RangeInclusive<Frame>`,
+/// rather than relying on `Deref` to implicitly expose its methods.
+impl AsRef<RangeInclusive<Frame>> for FrameRange {
+    fn as_ref(&self) -> &RangeInclusive<Frame> {
+        &self.0
+    }
+}
+impl AsMut<RangeInclusive<Frame>> for FrameRange {
+    fn as_mut(&mut self) -> &mut RangeInclusive<Frame> {
+        &mut self.0
+    }
+}
+
+/// Both bounds of a `FrameRange` are inclusive, just like the underlying `RangeInclusive<Frame>`.
+impl RangeBounds<Frame> for FrameRange {
+    fn start_bound(&self) -> Bound<&Frame> {
+        self.0.start_bound()
+    }
+    fn end_bound(&self) -> Bound<&Frame> {
+        self.0.end_bound()
+    }
+}
+
+impl From<Frame> for FrameRange {
+    /// Creates a `FrameRange` containing only the given `Frame`.
+    fn from(frame: Frame) -> FrameRange {
+        FrameRange::new(frame, frame)
+    }
+}
+
+impl<'a> From<&'a PhysicalMemoryArea> for FrameRange {
+    /// Creates a `FrameRange` covering the given area's entire byte extent,
+    /// the same as [`PhysicalMemoryArea::frame_range()`].
+    fn from(area: &'a PhysicalMemoryArea) -> FrameRange {
+        area.frame_range()
+    }
+}
+
+impl From<FrameRange> for PhysicalMemoryArea {
+    /// Creates a `PhysicalMemoryArea` covering the given `FrameRange`,
+    /// marked as usable RAM, i.e., with a `typ` of [`USABLE_MEMORY_AREA_TYPE`] and an `acpi` of `0`.
+    ///
+    /// Use [`FrameRange::as_physical_memory_area()`] to choose a different `typ` or `acpi`.
+    fn from(frames: FrameRange) -> PhysicalMemoryArea {
+        frames.as_physical_memory_area(USABLE_MEMORY_AREA_TYPE, 0)
+    }
+}
 
 impl IntoIterator for FrameRange {
     type Item = Frame;
@@ -427,30 +1843,262 @@ impl IntoIterator for FrameRange {
     }
 }
 
+/// Sums the number of `Frame`s across many `FrameRange`s, e.g., `ranges.into_iter().sum::<usize>()`.
+impl core::iter::Sum<FrameRange> for usize {
+    fn sum<I: Iterator<Item = FrameRange>>(iter: I) -> usize {
+        iter.map(|range| range.size_in_frames()).sum()
+    }
+}
+impl<'a> core::iter::Sum<&'a FrameRange> for usize {
+    fn sum<I: Iterator<Item = &'a FrameRange>>(iter: I) -> usize {
+        iter.map(|range| range.size_in_frames()).sum()
+    }
+}
 
-/// A virtual memory page, which contains the index of the page
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Page {
+
+/// A `FrameRange` whose number of frames has been computed once up front,
+/// created via [`FrameRange::with_cached_len()`].
+///
+/// This derefs to the inner `FrameRange`, but cannot be mutated, which keeps the cached length valid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CountedFrameRange {
+    range: FrameRange,
+    len: usize,
+}
+
+impl CountedFrameRange {
+    /// Returns the number of `Frame`s in this range, without recomputing it.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if this range contains no `Frame`s.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Consumes this `CountedFrameRange` and returns the inner `FrameRange`.
+    pub fn into_inner(self) -> FrameRange {
+        self.range
+    }
+}
+
+impl Deref for CountedFrameRange {
+    type Target = FrameRange;
+    fn deref(&self) -> &FrameRange {
+        &self.range
+    }
+}
+
+
+mod private {
+    /// Prevents `PageSize` from being implemented outside of this crate.
+    pub trait Sealed {}
+    impl Sealed for super::Size4KiB {}
+    impl Sealed for super::Size2MiB {}
+}
+
+/// A marker trait for the size of a virtual memory `Page`.
+///
+/// This allows pages of different sizes, e.g., 4KiB and 2MiB huge pages,
+/// to be distinct types that cannot be accidentally mixed together.
+///
+/// This trait is sealed, so only the page sizes defined in this crate can implement it.
+pub trait PageSize: private::Sealed + Copy + Eq + Ord + core::hash::Hash {
+    /// The size of a page of this type, in bytes.
+    const SIZE: usize;
+    /// The maximum number of a page of this type, i.e., the page containing the highest virtual address.
+    ///
+    /// For normal 4KiB pages, this is `MAX_PAGE_NUMBER`.
+    const MAX_NUMBER: usize = MAX_VIRTUAL_ADDRESS / Self::SIZE;
+}
+
+/// Marker type for a normal 4KiB page, the default size of a `Page`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Size4KiB {}
+impl PageSize for Size4KiB {
+    const SIZE: usize = PAGE_SIZE;
+}
+
+/// Marker type for a 2MiB huge page, which spans 512 normal 4KiB pages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Size2MiB {}
+impl PageSize for Size2MiB {
+    const SIZE: usize = Size4KiB::SIZE * 512;
+}
+
+
+/// A virtual memory page, which contains the index of the page.
+///
+/// The page size `S` defaults to `Size4KiB`.
+/// Pages of any size can be created, compared, offset with `Add`/`Sub`, and iterated over,
+/// but `Size4KiB` is the only size currently supported by `PageRange`, the page table index accessors,
+/// and the rest of the memory subsystem.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Page<S: PageSize = Size4KiB> {
     number: usize,
+    size: PhantomData<S>,
 }
-impl fmt::Debug for Page {
+impl<S: PageSize> fmt::Debug for Page<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "Page(v{:#X})", self.start_address())
     }
 }
+/// Displays this `Page` as its starting `VirtualAddress`, e.g., `v0x1000`,
+/// which is terser than its `Debug` form.
+impl<S: PageSize> fmt::Display for Page<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.start_address(), f)
+    }
+}
 
-impl Page {
-    /// Returns the `Page` that contains the given `VirtualAddress`.
-    pub const fn containing_address(virt_addr: VirtualAddress) -> Page {
+// zerocopy's `AsBytes` derive does not support type parameters, so it is implemented manually.
+//
+// SAFETY: `AsBytes` requires that every byte of a `Page<S>` is initialized, i.e., that it has no padding.
+// `Page` is `repr(transparent)`, so its layout is exactly that of its one non-zero-sized field, the `usize` number.
+// Its other field, `PhantomData<S>`, is zero-sized with an alignment of 1 for every type `S`,
+// so it adds neither bytes nor padding no matter which `S` is used.
+// `PageSize` is sealed, so the only possible `S` types are the uninhabited marker enums in this crate.
+unsafe impl<S: PageSize> AsBytes for Page<S> {
+    fn only_derive_is_allowed_to_implement_this_trait() { }
+}
+const_assert_eq!(core::mem::size_of::<Page<Size4KiB>>(), core::mem::size_of::<usize>());
+const_assert_eq!(core::mem::size_of::<Page<Size2MiB>>(), core::mem::size_of::<usize>());
+
+impl<S: PageSize> Page<S> {
+    /// Returns the `Page` of size `S` that contains the given `VirtualAddress`,
+    /// e.g., `Page::<Size2MiB>::containing_address_sized(virt_addr)`.
+    ///
+    /// For normal 4KiB pages, [`Page::containing_address()`] can be used instead
+    /// without having to specify the page size.
+    pub const fn containing_address_sized(virt_addr: VirtualAddress) -> Page<S> {
         Page {
-            number: virt_addr.value() / PAGE_SIZE,
+            number: virt_addr.value() / S::SIZE,
+            size: PhantomData,
         }
     }
 
     /// Returns the `VirtualAddress` as the start of this `Page`.
     pub const fn start_address(&self) -> VirtualAddress {
         // Cannot create VirtualAddress directly because the field is private
-        VirtualAddress::new_canonical(self.number * PAGE_SIZE)
+        VirtualAddress::new_canonical(self.number * S::SIZE)
+    }
+
+    /// Returns `true` if this `Page`'s number is a multiple of the given number of `pages`,
+    /// e.g., `512` to check whether it begins a 2MiB huge page.
+    ///
+    /// If `pages` is `0`, this returns `false`.
+    pub fn is_aligned_to(&self, pages: usize) -> bool {
+        pages != 0 && self.number % pages == 0
+    }
+
+    /// Returns the `Page` that is `rhs` pages after this one,
+    /// or `None` if that would exceed the maximum page number (`S::MAX_NUMBER`).
+    ///
+    /// Unlike `Add<usize>`, this does not clamp the result to the maximum page.
+    pub fn checked_add(self, rhs: usize) -> Option<Page<S>> {
+        match self.number.checked_add(rhs) {
+            Some(number) if number <= S::MAX_NUMBER => Some(Page { number, size: PhantomData }),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Page` that is `delta` pages away from this one, moving forward if `delta` is positive
+    /// or backward if it is negative, e.g., for relative walks in either direction.
+    ///
+    /// Like `Add<usize>` and `Sub<usize>`, this saturates at page `0` and at the maximum page number (`S::MAX_NUMBER`).
+    pub fn saturating_offset(self, delta: isize) -> Page<S> {
+        if delta >= 0 {
+            self + delta as usize
+        } else {
+            self - (delta as usize).wrapping_neg()
+        }
+    }
+
+    /// Returns the `Page` that is `rhs` pages before this one,
+    /// or `None` if that would go below page number `0`.
+    pub fn checked_sub(self, rhs: usize) -> Option<Page<S>> {
+        self.number.checked_sub(rhs).map(|number| Page { number, size: PhantomData })
+    }
+}
+
+impl Page {
+    /// Returns the `Page` that contains the given `VirtualAddress`.
+    pub const fn containing_address(virt_addr: VirtualAddress) -> Page {
+        Page {
+            number: Page::number_from_address(virt_addr),
+            size: PhantomData,
+        }
+    }
+
+    /// Returns the number of the `Page` that contains the given `VirtualAddress`.
+    pub const fn number_from_address(virt_addr: VirtualAddress) -> usize {
+        virt_addr.value() >> PAGE_SHIFT
+    }
+
+    /// Returns the `Page` that contains the given `VirtualAddress`,
+    /// or an error if that page's number would exceed `MAX_PAGE_NUMBER`.
+    ///
+    /// Use this instead of [`Page::containing_address()`] when validating addresses from untrusted sources.
+    ///
+    /// Note that under the current configuration, `MAX_PAGE_NUMBER` is the page containing `usize::MAX`,
+    /// so every `VirtualAddress` is within bounds and this never returns an error.
+    /// The check exists so that callers remain correct if the virtual address space is ever configured smaller.
+    pub fn containing_address_checked(virt_addr: VirtualAddress) -> Result<Page, MemoryStructError> {
+        let number = Page::number_from_address(virt_addr);
+        if number > MAX_PAGE_NUMBER {
+            return Err(MemoryStructError::PageNumberTooLarge);
+        }
+        Ok(Page { number, size: PhantomData })
+    }
+
+    /// Returns the `Page` with the maximum page number (`MAX_PAGE_NUMBER`),
+    /// e.g., as an initial value when tracking the lowest page seen so far.
+    pub const fn max() -> Page {
+        Page { number: MAX_PAGE_NUMBER, size: PhantomData }
+    }
+
+    /// Returns the `Page` that is `rhs` pages after this one,
+    /// panicking in debug builds if that would exceed the maximum page number (`MAX_PAGE_NUMBER`).
+    ///
+    /// In release builds, the page number instead wraps around to `0` past `MAX_PAGE_NUMBER`.
+    /// Unlike `Add<usize>`, this never silently clamps the result.
+    pub fn strict_add(self, rhs: usize) -> Page {
+        debug_assert!(
+            self.checked_add(rhs).is_some(),
+            "Page::strict_add(): page number overflowed MAX_PAGE_NUMBER"
+        );
+        Page { number: self.number.wrapping_add(rhs) & MAX_PAGE_NUMBER, size: PhantomData }
+    }
+
+    /// Returns a `PageRange` spanning from this `Page` to the given `end` `Page`, both inclusive.
+    ///
+    /// If `end` comes before this `Page`, an empty `PageRange` is returned
+    /// rather than a malformed, inverted one.
+    pub fn range_to(self, end: Page) -> PageRange {
+        if end < self {
+            PageRange::empty()
+        } else {
+            PageRange::new(self, end)
+        }
+    }
+
+    /// Returns the `Frame` with the same number as this `Page`.
+    ///
+    /// This is only meaningful for identity-mapped memory, in which each `Page` is mapped
+    /// to the `Frame` at the same address; otherwise, the two are unrelated.
+    pub const fn to_identity_frame(&self) -> Frame {
+        Frame { number: self.number }
+    }
+
+    /// Returns an iterator over `count` pages that are each `step` pages apart, starting from this `Page`.
+    ///
+    /// The iterator ends early if the next page would exceed the maximum page number (`MAX_PAGE_NUMBER`).
+    /// The `step` must be greater than `0`.
+    pub fn step_by_pages(&self, count: usize, step: usize) -> impl Iterator<Item = Page> {
+        debug_assert!(step > 0, "Page::step_by_pages(): step must be greater than 0");
+        core::iter::successors(Some(*self), move |prev| prev.checked_add(step)).take(count)
     }
 
     /// Returns the 9-bit part of this page's virtual address that is the index into the P4 page table entries list.
@@ -474,58 +2122,105 @@ impl Page {
     pub fn p1_index(&self) -> usize {
         (self.number >> 0) & 0x1FF
     }
+
+    /// Splits this `Page`'s number into one index per page table level, e.g., for walking multi-level page tables.
+    ///
+    /// Each entry of `levels` is the bit width of that level's index, ordered from the highest level down to the lowest;
+    /// the returned indices follow the same order.
+    /// For example, `levels` of `[9, 9, 9, 9]` yields the P4, P3, P2, and P1 indices on x86_64.
+    ///
+    /// An error is returned if there are more levels than the capacity `N` allows.
+    pub fn table_indices<N>(&self, levels: &[usize]) -> Result<heapless::Vec<usize, N>, MemoryStructError>
+        where N: ArrayLength<usize>
+    {
+        split_table_indices(self.number, levels)
+    }
 }
 
-impl Add<usize> for Page {
-    type Output = Page;
+/// Adding a number of pages to a `Page` saturates at the maximum page number (`S::MAX_NUMBER`).
+///
+/// Use [`Page::checked_add()`] to detect that case, or [`Page::strict_add()`] to panic in debug builds.
+impl<S: PageSize> Add<usize> for Page<S> {
+    type Output = Page<S>;
 
-    fn add(self, rhs: usize) -> Page {
+    fn add(self, rhs: usize) -> Page<S> {
         // cannot exceed max page number
         Page {
-            number: core::cmp::min(MAX_PAGE_NUMBER, self.number.saturating_add(rhs)),
+            number: core::cmp::min(S::MAX_NUMBER, self.number.saturating_add(rhs)),
+            size: PhantomData,
         }
     }
 }
 
-impl AddAssign<usize> for Page {
+impl<S: PageSize> AddAssign<usize> for Page<S> {
     fn add_assign(&mut self, rhs: usize) {
         *self = Page {
-            number: core::cmp::min(MAX_PAGE_NUMBER, self.number.saturating_add(rhs)),
+            number: core::cmp::min(S::MAX_NUMBER, self.number.saturating_add(rhs)),
+            size: PhantomData,
         };
     }
 }
 
-impl Sub<usize> for Page {
-    type Output = Page;
+impl<S: PageSize> Sub<usize> for Page<S> {
+    type Output = Page<S>;
 
-    fn sub(self, rhs: usize) -> Page {
+    fn sub(self, rhs: usize) -> Page<S> {
         Page {
             number: self.number.saturating_sub(rhs),
+            size: PhantomData,
         }
     }
 }
 
-impl SubAssign<usize> for Page {
+impl<S: PageSize> SubAssign<usize> for Page<S> {
     fn sub_assign(&mut self, rhs: usize) {
         *self = Page {
             number: self.number.saturating_sub(rhs),
+            size: PhantomData,
         };
     }
 }
 
+/// Subtracting one `Page` from another yields the number of pages between them,
+/// saturating at `0` if `rhs` comes after `self`.
+///
+/// Note that this differs from `Page - usize`, which yields a new `Page`
+/// that has been moved backwards by the given number of pages.
+impl<S: PageSize> Sub<Page<S>> for Page<S> {
+    type Output = usize;
+
+    fn sub(self, rhs: Page<S>) -> usize {
+        self.number.saturating_sub(rhs.number)
+    }
+}
+
+/// Compares this `Page`'s number with a raw page number, e.g., `page == 5` or `page < 100`.
+///
+/// As with `Frame`, only this direction is implemented, to keep `usize` comparisons elsewhere unambiguous.
+impl<S: PageSize> PartialEq<usize> for Page<S> {
+    fn eq(&self, other: &usize) -> bool {
+        self.number == *other
+    }
+}
+impl<S: PageSize> PartialOrd<usize> for Page<S> {
+    fn partial_cmp(&self, other: &usize) -> Option<core::cmp::Ordering> {
+        self.number.partial_cmp(other)
+    }
+}
+
 // Implementing these functions allow `Page` to be in an `Iterator`.
-unsafe impl Step for Page {
+unsafe impl<S: PageSize> Step for Page<S> {
     #[inline]
-    fn steps_between(start: &Page, end: &Page) -> Option<usize> {
+    fn steps_between(start: &Page<S>, end: &Page<S>) -> Option<usize> {
         Step::steps_between(&start.number, &end.number)
     }
     #[inline]
-    fn forward_checked(start: Page, count: usize) -> Option<Page> {
-        Step::forward_checked(start.number, count).map(|n| Page { number: n })
+    fn forward_checked(start: Page<S>, count: usize) -> Option<Page<S>> {
+        Step::forward_checked(start.number, count).map(|n| Page { number: n, size: PhantomData })
     }
     #[inline]
-    fn backward_checked(start: Page, count: usize) -> Option<Page> {
-        Step::backward_checked(start.number, count).map(|n| Page { number: n })
+    fn backward_checked(start: Page<S>, count: usize) -> Option<Page<S>> {
+        Step::backward_checked(start.number, count).map(|n| Page { number: n, size: PhantomData })
     }
 }
 
@@ -544,7 +2239,10 @@ impl PageRange {
 
     /// Creates a PageRange that will always yield `None`.
     pub const fn empty() -> PageRange {
-        PageRange::new(Page { number: 1 }, Page { number: 0 })
+        PageRange::new(
+            Page { number: 1, size: PhantomData },
+            Page { number: 0, size: PhantomData },
+        )
     }
 
     /// A convenience method for creating a new `PageRange`
@@ -558,6 +2256,39 @@ impl PageRange {
         PageRange::new(start_page, end_page)
     }
 
+    /// A convenience method for creating a new `PageRange`
+    /// that spans all `Page`s covering the given inclusive range of virtual addresses.
+    ///
+    /// If the range's end address comes before its start address, an empty `PageRange` is returned.
+    pub fn from_virt_addr_range(range: RangeInclusive<VirtualAddress>) -> PageRange {
+        let (start, end) = range.into_inner();
+        if end < start {
+            return PageRange::empty();
+        }
+        PageRange::new(Page::containing_address(start), Page::containing_address(end))
+    }
+
+    /// Creates a `PageRange` from the given sequence of `Page`s,
+    /// which must be contiguous, i.e., each one must come exactly one page after the previous one.
+    ///
+    /// Returns an error if the sequence is not contiguous.
+    /// An empty sequence produces an empty `PageRange`.
+    pub fn try_from_pages<I: IntoIterator<Item = Page>>(pages: I) -> Result<PageRange, MemoryStructError> {
+        let mut iter = pages.into_iter();
+        let start = match iter.next() {
+            Some(start) => start,
+            None => return Ok(PageRange::empty()),
+        };
+        let mut end = start;
+        for next in iter {
+            if end.checked_add(1) != Some(next) {
+                return Err(MemoryStructError::NotContiguous);
+            }
+            end = next;
+        }
+        Ok(PageRange::new(start, end))
+    }
+
     /// Returns the `VirtualAddress` of the starting `Page`.
     pub const fn start_address(&self) -> VirtualAddress {
         self.0.start().start_address()
@@ -571,9 +2302,25 @@ impl PageRange {
         self.0.end().number + 1 - self.0.start().number
     }
 
+    /// Returns the number of `Page`s in this range; an alias for [`PageRange::size_in_pages()`].
+    pub const fn len(&self) -> usize {
+        self.size_in_pages()
+    }
+
     /// Returns the size in number of bytes.
+    ///
+    /// This saturates at `usize::MAX` for a range that spans every page,
+    /// whose true byte size is one more than `usize::MAX`.
     pub const fn size_in_bytes(&self) -> usize {
-        self.size_in_pages() * PAGE_SIZE
+        self.size_in_pages().saturating_mul(PAGE_SIZE)
+    }
+
+    /// Returns `true` if this `PageRange` contains no `Page`s,
+    /// i.e., if its starting `Page` comes after its ending `Page`.
+    ///
+    /// A `PageRange` created by [`PageRange::empty()`] always satisfies this.
+    pub const fn is_empty(&self) -> bool {
+        self.0.start().number > self.0.end().number
     }
 
     /// Whether this `PageRange` contains the given `VirtualAddress`.
@@ -581,6 +2328,13 @@ impl PageRange {
         self.0.contains(&Page::containing_address(virt_addr))
     }
 
+    /// Whether this `PageRange` contains the given `Page`.
+    ///
+    /// An empty `PageRange` contains no `Page`s.
+    pub fn contains_page(&self, page: Page) -> bool {
+        self.0.contains(&page)
+    }
+
     /// Returns the offset of the given `VirtualAddress` within this `PageRange`,
     /// i.e., the difference between `virt_addr` and `self.start_address()`.
     /// If the given `VirtualAddress` is not covered by this range of `Page`s, this returns `None`.
@@ -598,24 +2352,445 @@ impl PageRange {
 
     /// Returns the `VirtualAddress` at the given `offset` into this mapping,  
     /// If the given `offset` is not covered by this range of `Page`s, this returns `None`.
+    ///
+    /// This is the inverse of [`PageRange::offset_of_address()`],
+    /// so the offset must be strictly less than `self.size_in_bytes()`.
     ///  
     /// # Examples
     /// If the page range covered addresses `0xFFFFFFFF80002000` to `0xFFFFFFFF80004000`,
     /// then calling `address_at_offset(0x1500)` would return `Some(0xFFFFFFFF80003500)`.
     pub fn address_at_offset(&self, offset: usize) -> Option<VirtualAddress> {
-        if offset <= self.size_in_bytes() {
+        if offset < self.size_in_bytes() {
             Some(self.start_address() + offset)
         }
         else {
             None
         }
     }
+
+    /// Returns a new, separate `PageRange` that is extended to include the given `Page`.
+    pub fn to_extended(&self, page_to_include: Page) -> PageRange {
+        // if the current PageRange was empty, return a new PageRange containing only the given page_to_include
+        if self.is_empty() {
+            return PageRange::new(page_to_include, page_to_include);
+        }
+
+        let start = core::cmp::min(self.0.start(), &page_to_include);
+        let end = core::cmp::max(self.0.end(), &page_to_include);
+        PageRange::new(*start, *end)
+    }
+
+    /// Returns a new, separate `PageRange` that is extended to include the `Page`
+    /// containing the given `VirtualAddress`.
+    pub fn to_extended_address(&self, virt_addr: VirtualAddress) -> PageRange {
+        self.to_extended(Page::containing_address(virt_addr))
+    }
+
+    /// Splits this `PageRange` into two at the `Page` containing the given `addr`,
+    /// returning the `Page`s before that `Page` and the `Page`s from that `Page` (inclusive) onwards.
+    ///
+    /// If `addr` is not page-aligned, the split is rounded down to the start of the `Page` containing it,
+    /// so that `addr` always falls within the second part.
+    /// A part is `None` if it would contain no `Page`s, e.g., if `addr` is outside of this range.
+    pub fn split_at_address(self, addr: VirtualAddress) -> (Option<PageRange>, Option<PageRange>) {
+        if self.is_empty() {
+            return (None, None);
+        }
+        let at = Page::containing_address(addr);
+        let (start, end) = (*self.0.start(), *self.0.end());
+        if at <= start {
+            (None, Some(self))
+        } else if at > end {
+            (Some(self), None)
+        } else {
+            (Some(PageRange::new(start, at - 1)), Some(PageRange::new(at, end)))
+        }
+    }
+
+    /// Returns a new `PageRange` whose end is moved forward by `pages` pages, keeping the same start.
+    ///
+    /// The end saturates at the maximum page number (`MAX_PAGE_NUMBER`).
+    /// An empty range has no bounds to extend, so it remains empty.
+    pub fn grow_end_by(self, pages: usize) -> PageRange {
+        if self.is_empty() {
+            return self;
+        }
+        PageRange::new(*self.0.start(), *self.0.end() + pages)
+    }
+
+    /// Returns a new `PageRange` whose start is moved backward by `pages` pages, keeping the same end.
+    ///
+    /// The start saturates at page `0`.
+    /// An empty range has no bounds to extend, so it remains empty.
+    pub fn grow_start_by(self, pages: usize) -> PageRange {
+        if self.is_empty() {
+            return self;
+        }
+        PageRange::new(*self.0.start() - pages, *self.0.end())
+    }
+
+    /// Returns the parts of this `PageRange` that are not covered by the `other` `PageRange`.
+    ///
+    /// The first element is the part of `self` that comes before `other`,
+    /// and the second element is the part of `self` that comes after `other`.
+    /// Either element will be `None` if there is no such leftover part.
+    ///
+    /// To loop over the leftover ranges, use `self.subtract(other).iter().flatten()`.
+    pub fn subtract(&self, other: &PageRange) -> [Option<PageRange>; 2] {
+        if self.is_empty() {
+            return [None, None];
+        }
+        if other.is_empty() || other.0.end() < self.0.start() || other.0.start() > self.0.end() {
+            return [Some(self.clone()), None];
+        }
+
+        let before = if other.0.start() > self.0.start() {
+            Some(PageRange::new(*self.0.start(), *other.0.start() - 1))
+        } else {
+            None
+        };
+        let after = if other.0.end() < self.0.end() {
+            Some(PageRange::new(*other.0.end() + 1, *self.0.end()))
+        } else {
+            None
+        };
+        [before, after]
+    }
+
+    /// Splits this `PageRange` into `n` contiguous sub-ranges whose sizes differ by at most one `Page`.
+    ///
+    /// The sub-ranges are yielded in ascending order, with the larger ones first,
+    /// such that their concatenation is the original `PageRange`.
+    /// This always yields exactly `n` ranges; if `n` is greater than the number of `Page`s in this range,
+    /// the trailing ranges will be empty.
+    ///
+    /// # Examples
+    /// Splitting a range of 10 pages into 3 parts yields ranges of 4, 3, and 3 pages.
+    pub fn split_into(&self, n: usize) -> impl Iterator<Item = PageRange> {
+        let start = *self.0.start();
+        let total = if self.is_empty() { 0 } else { self.size_in_pages() };
+        let (base, extra) = if n == 0 { (0, 0) } else { (total / n, total % n) };
+        (0..n).map(move |i| {
+            let size = base + if i < extra { 1 } else { 0 };
+            let offset = i * base + core::cmp::min(i, extra);
+            if size == 0 {
+                PageRange::empty()
+            } else {
+                PageRange::new(start + offset, start + (offset + size - 1))
+            }
+        })
+    }
+
+    /// Returns the largest sub-range of this `PageRange` that consists only of whole blocks of `pages` pages,
+    /// i.e., that starts at a multiple of `pages` and ends right before a multiple of `pages`,
+    /// e.g., `512` to find the part of this range that can be covered by 2MiB huge pages.
+    ///
+    /// The unaligned edges of this range are trimmed off.
+    /// Returns an empty `PageRange` if no whole block fits within this range or if `pages` is `0`.
+    pub fn aligned_subrange(&self, pages: usize) -> PageRange {
+        if pages == 0 || self.is_empty() {
+            return PageRange::empty();
+        }
+        let start = match self.0.start().number.checked_add(pages - 1) {
+            Some(n) => n / pages * pages,
+            None => return PageRange::empty(),
+        };
+        // the exclusive end, rounded down to a multiple of `pages`
+        let end = (self.0.end().number + 1) / pages * pages;
+        if end <= start {
+            PageRange::empty()
+        } else {
+            PageRange::new(Page { number: start, size: PhantomData }, Page { number: end - 1, size: PhantomData })
+        }
+    }
+
+    /// Returns the smallest `PageRange` that encloses this range and consists only of whole blocks of `pages` pages,
+    /// i.e., the start is rounded down and the end is rounded up to the block boundaries,
+    /// e.g., `512` to find the 2MiB huge page region(s) that this range falls within.
+    ///
+    /// This is the outer counterpart of [`PageRange::aligned_subrange()`].
+    /// The end is clamped to the maximum page number (`MAX_PAGE_NUMBER`).
+    /// If `pages` is `0`, this range is returned unchanged, and an empty range remains empty.
+    pub fn enclosing_aligned(&self, pages: usize) -> PageRange {
+        if pages == 0 || self.is_empty() {
+            return self.clone();
+        }
+        let start = self.0.start().number / pages * pages;
+        // the last page in the block containing this range's end
+        let end = (self.0.end().number / pages * pages).saturating_add(pages - 1);
+        PageRange::new(
+            Page { number: start, size: PhantomData },
+            Page { number: core::cmp::min(end, MAX_PAGE_NUMBER), size: PhantomData },
+        )
+    }
+
+    /// Returns the number of `Page`s in this `PageRange` that come at or after the given `Page`,
+    /// i.e., the number of pages remaining from `page` (inclusive) to the end of this range.
+    ///
+    /// If `page` comes before the start of this range, this returns the size of the whole range.
+    /// If `page` comes after the end of this range, this returns `0`.
+    pub fn pages_from(&self, page: Page) -> usize {
+        if self.is_empty() || page > *self.0.end() {
+            0
+        } else if page < *self.0.start() {
+            self.size_in_pages()
+        } else {
+            self.0.end().number + 1 - page.number
+        }
+    }
+
+    /// Returns the `Page` at the given `index` within this range, i.e., `start + index`,
+    /// or `None` if that would be beyond the inclusive end of this range.
+    pub fn nth_page(&self, index: usize) -> Option<Page> {
+        if index < self.size_in_pages() {
+            Some(*self.0.start() + index)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `Page` at the given `index` within this range, like indexing a slice.
+    ///
+    /// This is the panicking counterpart of [`PageRange::nth_page()`].
+    /// `PageRange` cannot implement `Index<usize>`, because that must return a reference,
+    /// but the `Page`s in a range are computed rather than stored.
+    ///
+    /// # Panics
+    /// Panics if `index` is beyond the end of this range.
+    pub fn page_at(&self, index: usize) -> Page {
+        match self.nth_page(index) {
+            Some(page) => page,
+            None => panic!("PageRange::page_at(): index {} out of bounds for range of {} pages", index, self.size_in_pages()),
+        }
+    }
+
+    /// Returns the number of `Page`s that this `PageRange` shares with the given `other` range,
+    /// or `0` if they are disjoint.
+    ///
+    /// This is cheaper than constructing the intersection of the two ranges just to count its size.
+    pub fn overlap_size(&self, other: &PageRange) -> usize {
+        if self.is_empty() || other.is_empty() {
+            return 0;
+        }
+        let start = core::cmp::max(self.0.start().number, other.0.start().number);
+        let end = core::cmp::min(self.0.end().number, other.0.end().number);
+        if start > end { 0 } else { end - start + 1 }
+    }
+
+    /// Returns `true` if every `Page` in the given slice lies within this range.
+    ///
+    /// This stops at the first `Page` outside of this range. An empty slice always returns `true`.
+    pub fn contains_all(&self, pages: &[Page]) -> bool {
+        pages.iter().all(|page| self.0.contains(page))
+    }
+
+    /// Returns the smallest `PageRange` that encloses both this range and the `other` range,
+    /// including any gap between them.
+    ///
+    /// Empty ranges are ignored: if only one of the two ranges is empty, the other is returned,
+    /// and if both are empty, an empty `PageRange` is returned.
+    pub fn bounding(&self, other: &PageRange) -> PageRange {
+        match (self.is_empty(), other.is_empty()) {
+            (true, true) => PageRange::empty(),
+            (true, false) => other.clone(),
+            (false, true) => self.clone(),
+            (false, false) => PageRange::new(
+                core::cmp::min(*self.0.start(), *other.0.start()),
+                core::cmp::max(*self.0.end(), *other.0.end()),
+            ),
+        }
+    }
+
+    /// Returns the number of `Page`s strictly between this range and the `other` range,
+    /// regardless of which one comes first.
+    ///
+    /// Adjacent ranges, in which one ends right before the other begins, have a gap of `Some(0)`.
+    /// Returns `None` if the two ranges overlap or if either range is empty.
+    pub fn gap_to(&self, other: &PageRange) -> Option<usize> {
+        if self.is_empty() || other.is_empty() {
+            return None;
+        }
+        let (low, high) = if self.0.start() <= other.0.start() { (self, other) } else { (other, self) };
+        if low.0.end() >= high.0.start() {
+            None
+        } else {
+            Some(high.0.start().number - low.0.end().number - 1)
+        }
+    }
+
+    /// Returns `true` if this range and the `other` range are exactly adjacent,
+    /// i.e., one ends right before the other begins, with no gap and no overlap.
+    ///
+    /// Overlapping ranges can also be merged, but are not adjacent. Empty ranges are never adjacent.
+    pub fn is_adjacent_to(&self, other: &PageRange) -> bool {
+        self.gap_to(other) == Some(0)
+    }
+
+    /// Returns a new `PageRange` with both bounds moved forward by `offset` bytes,
+    /// i.e., by `offset / PAGE_SIZE` pages.
+    ///
+    /// Returns an error if `offset` is not a multiple of `PAGE_SIZE`,
+    /// or if the shifted range would extend beyond the maximum page number.
+    /// An empty range remains empty.
+    pub fn shifted_by_bytes(&self, offset: usize) -> Result<PageRange, MemoryStructError> {
+        if offset % PAGE_SIZE != 0 {
+            return Err(MemoryStructError::NotPageAligned);
+        }
+        if self.is_empty() {
+            return Ok(PageRange::empty());
+        }
+        let count = offset / PAGE_SIZE;
+        match (self.0.start().checked_add(count), self.0.end().checked_add(count)) {
+            (Some(start), Some(end)) => Ok(PageRange::new(start, end)),
+            _ => Err(MemoryStructError::AddressOverflow),
+        }
+    }
+
+    /// Returns the parts of this `PageRange` that are not covered by any of the given `occupied` ranges,
+    /// i.e., the free gaps in this range, in ascending order.
+    ///
+    /// The `occupied` ranges must be sorted by their starting `Page` and must not overlap each other,
+    /// but they may extend beyond the bounds of this `PageRange`.
+    /// If this range is entirely occupied, the returned collection is empty.
+    /// An error is returned if there are more gaps than the capacity `N` allows.
+    pub fn gaps<N>(&self, occupied: &[PageRange]) -> Result<heapless::Vec<PageRange, N>, MemoryStructError>
+        where N: ArrayLength<PageRange>
+    {
+        let mut gaps: heapless::Vec<PageRange, N> = heapless::Vec::new();
+        if self.is_empty() {
+            return Ok(gaps);
+        }
+        let end = *self.0.end();
+        // The start of the next potential gap, or `None` if we've covered the rest of this range.
+        let mut cursor = Some(*self.0.start());
+        for occ in occupied.iter().filter(|r| !r.is_empty()) {
+            let current = match cursor {
+                Some(c) => c,
+                None => break,
+            };
+            if *occ.0.end() < current {
+                continue;
+            }
+            if *occ.0.start() > end {
+                break;
+            }
+            if *occ.0.start() > current {
+                gaps.push(PageRange::new(current, *occ.0.start() - 1))
+                    .map_err(|_| MemoryStructError::CapacityExceeded)?;
+            }
+            cursor = if *occ.0.end() >= end { None } else { Some(*occ.0.end() + 1) };
+        }
+        if let Some(current) = cursor {
+            gaps.push(PageRange::new(current, end))
+                .map_err(|_| MemoryStructError::CapacityExceeded)?;
+        }
+        Ok(gaps)
+    }
+
+    /// Consumes this `PageRange` and returns an iterator over its `Page`s in reverse order,
+    /// i.e., from the ending `Page` down to the starting `Page`, both inclusive.
+    ///
+    /// The returned iterator is itself double-ended, so it can also be walked forwards.
+    pub fn rev(self) -> impl DoubleEndedIterator<Item = Page> {
+        self.0.rev()
+    }
+
+    /// Returns an iterator over the starting `VirtualAddress` of each `Page` in this range,
+    /// in descending order from the last `Page` down to the first.
+    pub fn iter_addresses_rev(&self) -> impl Iterator<Item = VirtualAddress> {
+        self.clone().rev().map(|page| page.start_address())
+    }
+
+    /// Returns an iterator that pairs each `Page` in this range with the `Frame` computed for it by `f`,
+    /// in ascending order of pages.
+    ///
+    /// Unlike [`FrameRange::zip_pages()`], the frames need not be contiguous,
+    /// e.g., when each page's frame is chosen on demand or shared copy-on-write.
+    pub fn map_to_frames(&self, mut f: impl FnMut(Page) -> Frame) -> impl Iterator<Item = (Page, Frame)> {
+        self.clone().into_iter().map(move |page| (page, f(page)))
+    }
+
+    /// Returns the remainder of this `PageRange` after removing its first `pages` pages.
+    ///
+    /// If `pages` is greater than or equal to the size of this range, an empty `PageRange` is returned.
+    pub fn trim_start(self, pages: usize) -> PageRange {
+        if self.is_empty() || pages >= self.size_in_pages() {
+            PageRange::empty()
+        } else {
+            PageRange::new(*self.0.start() + pages, *self.0.end())
+        }
+    }
+
+    /// Returns the remainder of this `PageRange` after removing its last `pages` pages.
+    ///
+    /// If `pages` is greater than or equal to the size of this range, an empty `PageRange` is returned.
+    pub fn trim_end(self, pages: usize) -> PageRange {
+        if self.is_empty() || pages >= self.size_in_pages() {
+            PageRange::empty()
+        } else {
+            PageRange::new(*self.0.start(), *self.0.end() - pages)
+        }
+    }
+
+    /// Returns the prefix of this `PageRange` that covers at most `max_bytes` bytes,
+    /// rounded down to a whole number of pages.
+    ///
+    /// If `max_bytes` is at least the size of this range, this range is returned unchanged.
+    /// If `max_bytes` is less than one page, an empty `PageRange` is returned.
+    pub fn truncate_to_bytes(self, max_bytes: usize) -> PageRange {
+        let pages = max_bytes / PAGE_SIZE;
+        if self.is_empty() || pages == 0 {
+            PageRange::empty()
+        } else if pages >= self.size_in_pages() {
+            self
+        } else {
+            PageRange::new(*self.0.start(), *self.0.start() + (pages - 1))
+        }
+    }
+
+    /// Returns an iterator over each pair of consecutive `Page`s in this range, i.e., `(page, page + 1)`,
+    /// similar to `slice::windows(2)`.
+    ///
+    /// An empty or single-page range yields no pairs.
+    pub fn windows(&self) -> impl Iterator<Item = (Page, Page)> {
+        // every page except the last one begins a pair
+        self.clone().trim_end(1).into_iter().map(|page| (page, page + 1))
+    }
+
+    /// Returns an iterator over the `Page`s in this range whose number is a multiple of `stride`,
+    /// e.g., to visit only the pages of one cache color.
+    ///
+    /// The `stride` must be greater than `0`.
+    pub fn filter_aligned(&self, stride: usize) -> impl Iterator<Item = Page> {
+        debug_assert!(stride > 0, "PageRange::filter_aligned(): stride must be greater than 0");
+        self.clone().into_iter().filter(move |page| page.is_aligned_to(stride))
+    }
 }
 impl fmt::Debug for PageRange {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		write!(f, "{:?}", self.0)
 	}
 }
+impl PartialOrd for PageRange {
+    fn partial_cmp(&self, other: &PageRange) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for PageRange {
+    /// Orders `PageRange`s by their starting `Page`, then by their ending `Page`.
+    fn cmp(&self, other: &PageRange) -> core::cmp::Ordering {
+        self.0.start().cmp(other.0.start())
+            .then_with(|| self.0.end().cmp(other.0.end()))
+            // an exhausted range iterator is not equal to a fresh one, so this keeps `Ord` consistent with `Eq`
+            .then_with(|| RangeInclusive::is_empty(&self.0).cmp(&RangeInclusive::is_empty(&other.0)))
+    }
+}
+impl Default for PageRange {
+    /// Returns an empty `PageRange`, the same as `PageRange::empty()`.
+    fn default() -> PageRange {
+        PageRange::empty()
+    }
+}
 impl Deref for PageRange {
     type Target = RangeInclusive<Page>;
     fn deref(&self) -> &RangeInclusive<Page> {
@@ -627,6 +2802,35 @@ impl DerefMut for PageRange {
         &mut self.0
     }
 }
+/// The preferred way to explicitly access the inner `RangeInclusive<Page>`,
+/// rather than relying on `Deref` to implicitly expose its methods.
+impl AsRef<RangeInclusive<Page>> for PageRange {
+    fn as_ref(&self) -> &RangeInclusive<Page> {
+        &self.0
+    }
+}
+impl AsMut<RangeInclusive<Page>> for PageRange {
+    fn as_mut(&mut self) -> &mut RangeInclusive<Page> {
+        &mut self.0
+    }
+}
+
+/// Both bounds of a `PageRange` are inclusive, just like the underlying `RangeInclusive<Page>`.
+impl RangeBounds<Page> for PageRange {
+    fn start_bound(&self) -> Bound<&Page> {
+        self.0.start_bound()
+    }
+    fn end_bound(&self) -> Bound<&Page> {
+        self.0.end_bound()
+    }
+}
+
+impl From<Page> for PageRange {
+    /// Creates a `PageRange` containing only the given `Page`.
+    fn from(page: Page) -> PageRange {
+        PageRange::new(page, page)
+    }
+}
 
 impl IntoIterator for PageRange {
     type Item = Page;
@@ -637,18 +2841,175 @@ impl IntoIterator for PageRange {
     }
 }
 
+/// Sums the number of `Page`s across many `PageRange`s, e.g., `ranges.into_iter().sum::<usize>()`.
+impl core::iter::Sum<PageRange> for usize {
+    fn sum<I: Iterator<Item = PageRange>>(iter: I) -> usize {
+        iter.map(|range| range.size_in_pages()).sum()
+    }
+}
+impl<'a> core::iter::Sum<&'a PageRange> for usize {
+    fn sum<I: Iterator<Item = &'a PageRange>>(iter: I) -> usize {
+        iter.map(|range| range.size_in_pages()).sum()
+    }
+}
+
+
+/// A contiguous range of memory chunks, either virtual (`Page`s) or physical (`Frame`s).
+///
+/// This allows code to be written generically over both `PageRange` and `FrameRange`.
+pub trait AddressRange {
+    /// The type of memory chunk in this range, e.g., `Page` or `Frame`.
+    type Item;
+    /// The type of address covered by this range, e.g., `VirtualAddress` or `PhysicalAddress`.
+    type Addr: Copy;
+
+    /// Returns the address at the start of this range.
+    fn start_address(&self) -> Self::Addr;
+
+    /// Returns the size of this range in number of bytes.
+    fn size_in_bytes(&self) -> usize;
+
+    /// Whether this range contains the given address.
+    fn contains_address(&self, addr: Self::Addr) -> bool;
+
+    /// Returns the offset of the given address within this range,
+    /// or `None` if the address is not covered by this range.
+    fn offset_of_address(&self, addr: Self::Addr) -> Option<usize>;
+}
+
+impl AddressRange for FrameRange {
+    type Item = Frame;
+    type Addr = PhysicalAddress;
+
+    fn start_address(&self) -> PhysicalAddress {
+        FrameRange::start_address(self)
+    }
+    fn size_in_bytes(&self) -> usize {
+        FrameRange::size_in_bytes(self)
+    }
+    fn contains_address(&self, addr: PhysicalAddress) -> bool {
+        self.contains_phys_addr(addr)
+    }
+    fn offset_of_address(&self, addr: PhysicalAddress) -> Option<usize> {
+        FrameRange::offset_of_address(self, addr)
+    }
+}
+
+impl AddressRange for PageRange {
+    type Item = Page;
+    type Addr = VirtualAddress;
+
+    fn start_address(&self) -> VirtualAddress {
+        PageRange::start_address(self)
+    }
+    fn size_in_bytes(&self) -> usize {
+        PageRange::size_in_bytes(self)
+    }
+    fn contains_address(&self, addr: VirtualAddress) -> bool {
+        self.contains_virt_addr(addr)
+    }
+    fn offset_of_address(&self, addr: VirtualAddress) -> Option<usize> {
+        PageRange::offset_of_address(self, addr)
+    }
+}
+
+
+bitflags! {
+    /// The architecture-independent access permissions of a memory mapping.
+    #[derive(Default)]
+    pub struct MappingFlags: u8 {
+        const READABLE   = 1 << 0;
+        const WRITABLE   = 1 << 1;
+        const EXECUTABLE = 1 << 2;
+    }
+}
+
+impl MappingFlags {
+    /// Returns the access permissions granted by the given page table entry flags.
+    ///
+    /// Flags without the `PRESENT` bit grant no permissions at all.
+    pub fn from_entry_flags(flags: EntryFlags) -> MappingFlags {
+        let mut mapping_flags = MappingFlags::empty();
+        if !flags.contains(EntryFlags::PRESENT) {
+            return mapping_flags;
+        }
+        mapping_flags |= MappingFlags::READABLE;
+        if flags.is_writable() {
+            mapping_flags |= MappingFlags::WRITABLE;
+        }
+        if flags.is_executable() {
+            mapping_flags |= MappingFlags::EXECUTABLE;
+        }
+        mapping_flags
+    }
+}
+
 
 /// The address bounds and mapping flags of a section's memory region.
 #[derive(Debug)]
 pub struct SectionMemoryBounds {
     /// The starting virtual address and physical address.
     pub start: (VirtualAddress, PhysicalAddress),
-    /// The ending virtual address and physical address.
+    /// The ending virtual address and physical address, which are exclusive bounds.
     pub end: (VirtualAddress, PhysicalAddress),
     /// The page table entry flags that should be used for mapping this section.
     pub flags: EntryFlags,
 }
 
+impl SectionMemoryBounds {
+    /// Returns the range of `Page`s covering this section's virtual addresses.
+    ///
+    /// Because the `end` bound is exclusive, the last `Page` is the one containing the byte before `end.0`.
+    /// If the section is empty, an empty `PageRange` is returned.
+    pub fn page_range(&self) -> PageRange {
+        if self.end.0 <= self.start.0 {
+            PageRange::empty()
+        } else {
+            PageRange::new(Page::containing_address(self.start.0), Page::containing_address(self.end.0 - 1))
+        }
+    }
+
+    /// Returns the range of `Frame`s covering this section's physical addresses.
+    ///
+    /// Because the `end` bound is exclusive, the last `Frame` is the one containing the byte before `end.1`.
+    /// If the section is empty, an empty `FrameRange` is returned.
+    pub fn frame_range(&self) -> FrameRange {
+        if self.end.1 <= self.start.1 {
+            FrameRange::empty()
+        } else {
+            FrameRange::new(Frame::containing_address(self.start.1), Frame::containing_address(self.end.1 - 1))
+        }
+    }
+
+    /// Returns the access permissions of this section, derived from its page table entry `flags`.
+    pub fn mapping_flags(&self) -> MappingFlags {
+        MappingFlags::from_entry_flags(self.flags)
+    }
+
+    /// Returns the size of this section in bytes, based on its virtual address bounds.
+    ///
+    /// If the `end` virtual address comes before the `start`, this returns `0`.
+    pub fn size_in_bytes(&self) -> usize {
+        self.end.0.value().saturating_sub(self.start.0.value())
+    }
+
+    /// Checks that these bounds are well-formed, i.e., that
+    /// * the `start` comes at or before the `end` for both the virtual and physical addresses, and
+    /// * the virtual and physical spans have the same length.
+    pub fn validate(&self) -> Result<(), MemoryStructError> {
+        if self.start.0 > self.end.0 {
+            return Err(MemoryStructError::InvalidVirtualRange);
+        }
+        if self.start.1 > self.end.1 {
+            return Err(MemoryStructError::InvalidPhysicalRange);
+        }
+        if self.end.0.value() - self.start.0.value() != self.end.1.value() - self.start.1.value() {
+            return Err(MemoryStructError::MismatchedSpans);
+        }
+        Ok(())
+    }
+}
+
 /// The address bounds and flags of the initial kernel sections that need mapping. 
 /// 
 /// It contains three main items, in which each item includes all sections that have identical flags:
@@ -664,3 +3025,56 @@ pub struct AggregatedSectionMemoryBounds {
    pub data:   SectionMemoryBounds,
    pub stack:  SectionMemoryBounds,
 }
+
+impl AggregatedSectionMemoryBounds {
+    /// Returns an iterator over the bounds of each section,
+    /// in the order `text`, `rodata`, `data`, `stack`.
+    pub fn iter(&self) -> impl Iterator<Item = &SectionMemoryBounds> {
+        core::iter::once(&self.text)
+            .chain(core::iter::once(&self.rodata))
+            .chain(core::iter::once(&self.data))
+            .chain(core::iter::once(&self.stack))
+    }
+
+    /// Returns an iterator over mutable references to the bounds of each section,
+    /// in the same order as [`AggregatedSectionMemoryBounds::iter()`].
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut SectionMemoryBounds> {
+        core::iter::once(&mut self.text)
+            .chain(core::iter::once(&mut self.rodata))
+            .chain(core::iter::once(&mut self.data))
+            .chain(core::iter::once(&mut self.stack))
+    }
+
+    /// Returns the smallest `PageRange` that covers the `text`, `rodata`, and `data` sections,
+    /// and also the `stack` if `include_stack` is `true`, e.g., to log how much memory the kernel image occupies.
+    ///
+    /// Any gaps between the sections are included in the returned range.
+    pub fn bounding_page_range(&self, include_stack: bool) -> PageRange {
+        self.iter()
+            .take(if include_stack { 4 } else { 3 })
+            .fold(PageRange::empty(), |bounds, section| bounds.bounding(&section.page_range()))
+    }
+}
+
+/// The by-value iterator over the four `SectionMemoryBounds` in an `AggregatedSectionMemoryBounds`.
+type SectionMemoryBoundsIntoIter = core::iter::Chain<
+    core::iter::Chain<
+        core::iter::Chain<core::iter::Once<SectionMemoryBounds>, core::iter::Once<SectionMemoryBounds>>,
+        core::iter::Once<SectionMemoryBounds>,
+    >,
+    core::iter::Once<SectionMemoryBounds>,
+>;
+
+/// Consumes the aggregated bounds into the bounds of each section,
+/// in the order `text`, `rodata`, `data`, `stack`.
+impl IntoIterator for AggregatedSectionMemoryBounds {
+    type Item = SectionMemoryBounds;
+    type IntoIter = SectionMemoryBoundsIntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        core::iter::once(self.text)
+            .chain(core::iter::once(self.rodata))
+            .chain(core::iter::once(self.data))
+            .chain(core::iter::once(self.stack))
+    }
+}
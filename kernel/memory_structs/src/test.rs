@@ -0,0 +1,1595 @@
+//! Tests for the memory types in this crate.
+//!
+//! To run these, execute: `cargo test` in this crate's directory.
+
+use super::*;
+use std::vec::Vec;
+
+fn page(number: usize) -> Page {
+    Page { number, size: PhantomData }
+}
+
+fn frame(number: usize) -> Frame {
+    Frame { number }
+}
+
+#[test]
+fn page_range_to_extended() {
+    let range = PageRange::new(page(4), page(6));
+    assert_eq!(range.to_extended(page(2)), PageRange::new(page(2), page(6)));
+    assert_eq!(range.to_extended(page(9)), PageRange::new(page(4), page(9)));
+    assert_eq!(range.to_extended(page(5)), range);
+    assert_eq!(PageRange::empty().to_extended(page(7)), PageRange::new(page(7), page(7)));
+
+    // should behave exactly like the existing `FrameRange::to_extended()`
+    let frames = FrameRange::new(frame(4), frame(6));
+    assert_eq!(frames.to_extended(frame(2)), FrameRange::new(frame(2), frame(6)));
+    assert_eq!(FrameRange::empty().to_extended(frame(7)), FrameRange::new(frame(7), frame(7)));
+}
+
+#[test]
+fn page_range_to_extended_address() {
+    let range = PageRange::new(page(4), page(6));
+    let extended = range.to_extended_address(VirtualAddress::new_canonical(8 * PAGE_SIZE + 0x10));
+    assert_eq!(extended, PageRange::new(page(4), page(8)));
+}
+
+#[test]
+fn frame_range_to_extended_address() {
+    let range = FrameRange::new(frame(4), frame(6));
+    let inside = PhysicalAddress::new_canonical(5 * PAGE_SIZE + 0x123);
+    assert_eq!(range.to_extended_address(inside), range);
+    let above = PhysicalAddress::new_canonical(10 * PAGE_SIZE);
+    assert_eq!(range.to_extended_address(above), FrameRange::new(frame(4), frame(10)));
+}
+
+#[test]
+fn default_ranges_are_empty() {
+    assert!(FrameRange::default().into_iter().next().is_none());
+    assert!(PageRange::default().into_iter().next().is_none());
+    assert!(FrameRange::default().is_empty());
+    assert_eq!(PageRange::default(), PageRange::empty());
+}
+
+#[test]
+fn range_is_empty() {
+    assert!(FrameRange::empty().is_empty());
+    assert!(PageRange::empty().is_empty());
+    assert!(!FrameRange::new(frame(3), frame(3)).is_empty());
+    assert!(!PageRange::new(page(3), page(3)).is_empty());
+}
+
+#[test]
+fn address_as_bytes_round_trip() {
+    use zerocopy::LayoutVerified;
+
+    let vaddr = VirtualAddress::new_canonical(0xFFFF_FFFF_8012_3456);
+    let read_back = LayoutVerified::<_, VirtualAddress>::new(vaddr.as_bytes()).map(|v| *v);
+    assert_eq!(read_back, Some(vaddr));
+
+    let paddr = PhysicalAddress::new_canonical(0x1234_5000);
+    let read_back = LayoutVerified::<_, PhysicalAddress>::new(paddr.as_bytes()).map(|p| *p);
+    assert_eq!(read_back, Some(paddr));
+
+    // `Frame` and `Page` must keep the exact layout of their `usize` number.
+    assert_eq!(frame(0x42).as_bytes(), 0x42usize.as_bytes());
+    assert_eq!(page(0x42).as_bytes(), 0x42usize.as_bytes());
+}
+
+#[test]
+fn physical_memory_area_from_bytes() {
+    use zerocopy::LayoutVerified;
+
+    assert_eq!(core::mem::size_of::<PhysicalMemoryArea>(), 24);
+
+    // base_addr, size_in_bytes, then `typ` and `acpi` packed into one little-endian word.
+    let raw: [usize; 3] = [0x10_0000, 0x20_0000, (7 << 32) | 1];
+    let area = LayoutVerified::<_, PhysicalMemoryArea>::new(raw.as_bytes()).unwrap();
+    assert_eq!(area.base_addr, PhysicalAddress::new_canonical(0x10_0000));
+    assert_eq!(area.size_in_bytes, 0x20_0000);
+    assert_eq!(area.typ, 1);
+    assert_eq!(area.acpi, 7);
+    assert_eq!(area.as_bytes(), raw.as_bytes());
+}
+
+#[test]
+fn address_range_trait_is_generic() {
+    fn end_offset<R: AddressRange>(range: &R, addr: R::Addr) -> Option<usize> {
+        if range.contains_address(addr) {
+            range.offset_of_address(addr).map(|offset| range.size_in_bytes() - offset)
+        } else {
+            None
+        }
+    }
+
+    let pages = PageRange::new(page(2), page(3));
+    let frames = FrameRange::new(frame(2), frame(3));
+    assert_eq!(end_offset(&pages, VirtualAddress::new_canonical(0x3000)), Some(0x1000));
+    assert_eq!(end_offset(&frames, PhysicalAddress::new_canonical(0x2800)), Some(0x1800));
+    assert_eq!(end_offset(&frames, PhysicalAddress::new_canonical(0x4000)), None);
+    assert_eq!(AddressRange::start_address(&frames), PhysicalAddress::new_canonical(0x2000));
+}
+
+#[test]
+fn page_and_frame_is_aligned_to() {
+    assert!(page(0).is_aligned_to(512));
+    assert!(page(1024).is_aligned_to(512));
+    assert!(!page(513).is_aligned_to(512));
+    assert!(page(513).is_aligned_to(1));
+    assert!(!page(512).is_aligned_to(0));
+    assert!(frame(512).is_aligned_to(512));
+    assert!(!frame(511).is_aligned_to(512));
+    assert!(frame(7).is_aligned_to(1));
+}
+
+#[test]
+fn page_range_subtract() {
+    let range = PageRange::new(page(10), page(20));
+    let pr = |start, end| Some(PageRange::new(page(start), page(end)));
+
+    // `other` in the middle, at either end, covering everything, and disjoint.
+    assert_eq!(range.subtract(&PageRange::new(page(12), page(15))), [pr(10, 11), pr(16, 20)]);
+    assert_eq!(range.subtract(&PageRange::new(page(5), page(12))),  [None, pr(13, 20)]);
+    assert_eq!(range.subtract(&PageRange::new(page(18), page(25))), [pr(10, 17), None]);
+    assert_eq!(range.subtract(&PageRange::new(page(10), page(20))), [None, None]);
+    assert_eq!(range.subtract(&PageRange::new(page(0), page(30))),  [None, None]);
+    assert_eq!(range.subtract(&PageRange::new(page(21), page(30))), [pr(10, 20), None]);
+    assert_eq!(range.subtract(&PageRange::empty()), [pr(10, 20), None]);
+    assert_eq!(PageRange::empty().subtract(&range), [None, None]);
+
+    let total: usize = range.subtract(&PageRange::new(page(12), page(15)))
+        .iter()
+        .flatten()
+        .map(|r| r.size_in_pages())
+        .sum();
+    assert_eq!(total, 7);
+}
+
+#[test]
+fn range_split_into() {
+    let range = PageRange::new(page(100), page(109));
+    let parts: Vec<PageRange> = range.split_into(3).collect();
+    assert_eq!(parts, [
+        PageRange::new(page(100), page(103)),
+        PageRange::new(page(104), page(106)),
+        PageRange::new(page(107), page(109)),
+    ]);
+
+    let sizes: Vec<usize> = FrameRange::new(frame(0), frame(9))
+        .split_into(3)
+        .map(|r| r.size_in_frames())
+        .collect();
+    assert_eq!(sizes, [4, 3, 3]);
+
+    // more parts than pages: trailing parts are empty
+    let parts: Vec<PageRange> = PageRange::new(page(1), page(2)).split_into(4).collect();
+    assert_eq!(parts.len(), 4);
+    assert!(!parts[1].is_empty() && parts[2].is_empty() && parts[3].is_empty());
+    assert_eq!(range.split_into(0).count(), 0);
+}
+
+#[test]
+fn ranges_sort_by_start() {
+    let mut ranges = vec![
+        FrameRange::new(frame(30), frame(31)),
+        FrameRange::new(frame(5), frame(9)),
+        FrameRange::new(frame(5), frame(6)),
+        FrameRange::new(frame(12), frame(20)),
+    ];
+    ranges.sort();
+    let starts: Vec<PhysicalAddress> = ranges.iter().map(|r| r.start_address()).collect();
+    assert!(starts.windows(2).all(|w| w[0] <= w[1]));
+    assert_eq!(ranges[0], FrameRange::new(frame(5), frame(6)));
+    assert_eq!(ranges[1], FrameRange::new(frame(5), frame(9)));
+
+    let mut pages = vec![PageRange::new(page(8), page(9)), PageRange::new(page(1), page(2))];
+    pages.sort();
+    assert_eq!(pages[0], PageRange::new(page(1), page(2)));
+}
+
+#[test]
+fn page_and_frame_checked_arithmetic() {
+    assert_eq!(frame(MAX_FRAME_NUMBER - 1).checked_add(1), Some(frame(MAX_FRAME_NUMBER)));
+    assert_eq!(frame(MAX_FRAME_NUMBER).checked_add(1), None);
+    assert_eq!(frame(1).checked_add(usize::MAX), None);
+    assert_eq!(frame(1).checked_sub(1), Some(frame(0)));
+    assert_eq!(frame(0).checked_sub(1), None);
+
+    assert_eq!(page(MAX_PAGE_NUMBER).checked_add(0), Some(page(MAX_PAGE_NUMBER)));
+    assert_eq!(page(MAX_PAGE_NUMBER).checked_add(1), None);
+    assert_eq!(page(5).checked_sub(6), None);
+    assert_eq!(page(5).checked_sub(5), Some(page(0)));
+}
+
+#[test]
+fn range_pages_from() {
+    let range = PageRange::new(page(10), page(19));
+    assert_eq!(range.pages_from(page(5)), 10);
+    assert_eq!(range.pages_from(page(10)), 10);
+    assert_eq!(range.pages_from(page(15)), 5);
+    assert_eq!(range.pages_from(page(19)), 1);
+    assert_eq!(range.pages_from(page(20)), 0);
+    assert_eq!(PageRange::empty().pages_from(page(0)), 0);
+
+    let frames = FrameRange::new(frame(10), frame(19));
+    assert_eq!(frames.frames_from(frame(0)), 10);
+    assert_eq!(frames.frames_from(frame(12)), 8);
+    assert_eq!(frames.frames_from(frame(100)), 0);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn address_bytemuck_cast_slice() {
+    let addrs = [PhysicalAddress::new_canonical(0x1000), PhysicalAddress::new_canonical(0x2000)];
+    let raw: &[usize] = bytemuck::cast_slice(&addrs);
+    assert_eq!(raw, &[0x1000, 0x2000]);
+    let back: &[PhysicalAddress] = bytemuck::cast_slice(raw);
+    assert_eq!(back, &addrs);
+}
+
+#[test]
+fn frame_range_iter_aligned_blocks() {
+    // spans the 2MiB boundary at frame 512, and covers one full 2MiB block
+    let range = FrameRange::new(frame(510), frame(1025));
+    let blocks: Vec<(FrameRange, usize)> = range.iter_aligned_blocks(&[512, 1]).collect();
+    assert_eq!(blocks, [
+        (FrameRange::new(frame(510), frame(510)), 1),
+        (FrameRange::new(frame(511), frame(511)), 1),
+        (FrameRange::new(frame(512), frame(1023)), 512),
+        (FrameRange::new(frame(1024), frame(1024)), 1),
+        (FrameRange::new(frame(1025), frame(1025)), 1),
+    ]);
+
+    // too small for a large block
+    let small = FrameRange::new(frame(512), frame(515));
+    assert!(small.iter_aligned_blocks(&[512, 1]).all(|(_, size)| size == 1));
+    assert_eq!(FrameRange::empty().iter_aligned_blocks(&[512, 1]).count(), 0);
+}
+
+#[test]
+fn address_page_and_frame_base() {
+    let vaddr = VirtualAddress::new_canonical(0xFFFF_FFFF_8000_1234);
+    assert_eq!(vaddr.page_base(), VirtualAddress::new_canonical(0xFFFF_FFFF_8000_1000));
+    assert_eq!(vaddr.page_base() + vaddr.page_offset(), vaddr);
+    assert_eq!(vaddr.page_base(), Page::containing_address(vaddr).start_address());
+
+    let paddr = PhysicalAddress::new_canonical(0x5FFF);
+    assert_eq!(paddr.frame_base(), PhysicalAddress::new_canonical(0x5000));
+    assert_eq!(paddr.frame_base() + paddr.frame_offset(), paddr);
+    const BASE: PhysicalAddress = PhysicalAddress::zero().frame_base();
+    assert_eq!(BASE, PhysicalAddress::zero());
+}
+
+#[test]
+fn page_and_frame_sub_distance() {
+    assert_eq!(frame(10) - frame(4), 6usize);
+    assert_eq!(frame(4) - frame(10), 0usize);
+    assert_eq!(frame(10) - 4, frame(6));
+    assert_eq!(page(10) - page(10), 0usize);
+    assert_eq!(page(12) - page(2), 10usize);
+    assert_eq!(page(12) - 2, page(10));
+}
+
+#[test]
+fn frame_range_coalesce() {
+    use heapless::consts::{U2, U8};
+
+    let ranges = vec![
+        FrameRange::new(frame(20), frame(25)),
+        FrameRange::new(frame(1), frame(3)),
+        FrameRange::new(frame(10), frame(12)),
+        FrameRange::new(frame(4), frame(5)),   // adjacent to 1..=3
+        FrameRange::new(frame(22), frame(30)), // overlaps 20..=25
+        FrameRange::empty(),
+        FrameRange::new(frame(11), frame(11)), // contained within 10..=12
+    ];
+    let merged: heapless::Vec<FrameRange, U8> = FrameRange::coalesce(ranges.clone()).unwrap();
+    assert_eq!(&merged[..], &[
+        FrameRange::new(frame(1), frame(5)),
+        FrameRange::new(frame(10), frame(12)),
+        FrameRange::new(frame(20), frame(30)),
+    ][..]);
+
+    assert!(FrameRange::coalesce::<U2, _>(ranges).is_err());
+}
+
+#[test]
+fn page_range_gaps() {
+    use heapless::consts::{U1, U4};
+
+    let range = PageRange::new(page(0), page(99));
+    let occupied = [
+        PageRange::new(page(10), page(19)),
+        PageRange::new(page(30), page(39)),
+        PageRange::new(page(90), page(150)),
+    ];
+    let gaps: heapless::Vec<PageRange, U4> = range.gaps(&occupied).unwrap();
+    assert_eq!(&gaps[..], &[
+        PageRange::new(page(0), page(9)),   // leading gap
+        PageRange::new(page(20), page(29)), // middle gap
+        PageRange::new(page(40), page(89)), // middle gap
+    ][..]);
+
+    let trailing: heapless::Vec<PageRange, U4> = range.gaps(&occupied[..2]).unwrap();
+    assert_eq!(trailing.last(), Some(&PageRange::new(page(40), page(99))));
+
+    let full: heapless::Vec<PageRange, U4> = range.gaps(&[PageRange::new(page(0), page(99))]).unwrap();
+    assert!(full.is_empty());
+    let none_occupied: heapless::Vec<PageRange, U1> = range.gaps(&[]).unwrap();
+    assert_eq!(&none_occupied[..], &[range.clone()][..]);
+    assert!(range.gaps::<U1>(&occupied).is_err());
+}
+
+#[test]
+fn physical_memory_area_split_around() {
+    fn bounds(area: Option<PhysicalMemoryArea>) -> Option<(usize, usize, u32, u32)> {
+        area.map(|a| (a.base_addr.value(), a.size_in_bytes, a.typ, a.acpi))
+    }
+    let area = PhysicalMemoryArea::new(PhysicalAddress::new_canonical(0x10000), 0x10000, 1, 3);
+
+    // hole in the center
+    let (before, after) = area.split_around(&FrameRange::new(frame(0x14), frame(0x15)));
+    assert_eq!(bounds(before), Some((0x10000, 0x4000, 1, 3)));
+    assert_eq!(bounds(after), Some((0x16000, 0xA000, 1, 3)));
+
+    // hole touching the start and the end
+    let (before, after) = area.split_around(&FrameRange::new(frame(0x10), frame(0x11)));
+    assert_eq!((bounds(before), bounds(after)), (None, Some((0x12000, 0xE000, 1, 3))));
+    let (before, after) = area.split_around(&FrameRange::new(frame(0x1F), frame(0x30)));
+    assert_eq!((bounds(before), bounds(after)), (Some((0x10000, 0xF000, 1, 3)), None));
+
+    // hole outside of the area
+    let (before, after) = area.split_around(&FrameRange::new(frame(0x40), frame(0x41)));
+    assert_eq!((bounds(before), bounds(after)), (Some((0x10000, 0x10000, 1, 3)), None));
+    let (before, after) = area.split_around(&FrameRange::new(frame(0x1), frame(0x2)));
+    assert_eq!((bounds(before), bounds(after)), (None, Some((0x10000, 0x10000, 1, 3))));
+}
+
+#[test]
+fn page_and_frame_range_to() {
+    assert_eq!(page(3).range_to(page(7)).size_in_pages(), 5);
+    assert_eq!(page(3).range_to(page(3)).size_in_pages(), 1);
+    assert!(page(7).range_to(page(3)).is_empty());
+    assert_eq!(page(7).range_to(page(3)), PageRange::empty());
+    assert_eq!(frame(3).range_to(frame(7)), FrameRange::new(frame(3), frame(7)));
+    assert!(frame(7).range_to(frame(3)).is_empty());
+}
+
+#[test]
+fn address_offset_into() {
+    // mirrors the `offset_of_address()` example: pages covering 0x2000 to 0x4000
+    let pages = PageRange::new(page(2), page(3));
+    assert_eq!(VirtualAddress::new_canonical(0x3500).offset_into(&pages), Some(0x1500));
+    assert_eq!(VirtualAddress::new_canonical(0x3500).offset_into(&pages), pages.offset_of_address(VirtualAddress::new_canonical(0x3500)));
+    assert_eq!(VirtualAddress::new_canonical(0x1FFF).offset_into(&pages), None);
+
+    let frames = FrameRange::new(frame(2), frame(3));
+    assert_eq!(PhysicalAddress::new_canonical(0x3500).offset_into(&frames), Some(0x1500));
+    assert_eq!(PhysicalAddress::new_canonical(0x4000).offset_into(&frames), None);
+}
+
+#[test]
+fn page_range_address_at_offset_round_trip() {
+    let range = PageRange::new(page(2), page(3));
+    for offset in (0..range.size_in_bytes()).step_by(0x80).chain(core::iter::once(range.size_in_bytes() - 1)) {
+        let vaddr = range.address_at_offset(offset).unwrap();
+        assert_eq!(range.offset_of_address(vaddr), Some(offset));
+    }
+    assert_eq!(range.address_at_offset(range.size_in_bytes()), None);
+    assert_eq!(range.address_at_offset(0x1500), Some(VirtualAddress::new_canonical(0x3500)));
+}
+
+#[test]
+fn frame_range_offsets() {
+    // frames covering 0x2000 to 0x4000
+    let range = FrameRange::new(frame(2), frame(3));
+    assert_eq!(range.offset_of_address(PhysicalAddress::new_canonical(0x3500)), Some(0x1500));
+    assert_eq!(range.offset_of_address(PhysicalAddress::new_canonical(0x4000)), None);
+    assert_eq!(range.address_at_offset(0x1500), Some(PhysicalAddress::new_canonical(0x3500)));
+    assert_eq!(range.address_at_offset(0x2000), None);
+    assert_eq!(FrameRange::empty().address_at_offset(0), None);
+    for offset in (0..0x2000).step_by(0x100) {
+        let paddr = range.address_at_offset(offset).unwrap();
+        assert_eq!(range.offset_of_address(paddr), Some(offset));
+    }
+}
+
+#[test]
+fn range_from_address_range() {
+    let v = VirtualAddress::new_canonical;
+    assert_eq!(PageRange::from_virt_addr_range(v(0x1010)..=v(0x1FFF)), PageRange::new(page(1), page(1)));
+    assert_eq!(PageRange::from_virt_addr_range(v(0x1FFF)..=v(0x3000)), PageRange::new(page(1), page(3)));
+    assert!(PageRange::from_virt_addr_range(v(0x3000)..=v(0x1000)).is_empty());
+
+    let p = PhysicalAddress::new_canonical;
+    assert_eq!(FrameRange::from_phys_addr_range(p(0x5000)..=p(0x5001)), FrameRange::new(frame(5), frame(5)));
+    assert_eq!(FrameRange::from_phys_addr_range(p(0x5000)..=p(0x7000)), FrameRange::new(frame(5), frame(7)));
+    assert!(FrameRange::from_phys_addr_range(p(0x7000)..=p(0x5000)).is_empty());
+    // unaligned endpoints within one frame, and on either side of a frame boundary
+    assert_eq!(FrameRange::from_phys_addr_range(p(0x5123)..=p(0x5FFF)), FrameRange::new(frame(5), frame(5)));
+    assert_eq!(FrameRange::from_phys_addr_range(p(0x5FFF)..=p(0x6000)), FrameRange::new(frame(5), frame(6)));
+}
+
+#[test]
+fn range_from_single_frame_or_page() {
+    fn frames_in(range: impl Into<FrameRange>) -> usize {
+        range.into().size_in_frames()
+    }
+    assert_eq!(frames_in(frame(5)), 1);
+    assert_eq!(frames_in(FrameRange::new(frame(5), frame(8))), 4);
+    assert_eq!(FrameRange::from(frame(5)), FrameRange::new(frame(5), frame(5)));
+    assert_eq!(PageRange::from(page(9)), PageRange::new(page(9), page(9)));
+}
+
+#[test]
+fn physical_address_new_validates_high_bits() {
+    assert_eq!(PhysicalAddress::new(0x1234_5000).map(|p| p.value()), Ok(0x1234_5000));
+    assert!(PhysicalAddress::new((1 << MAX_PHYSICAL_ADDRESS_BITS) - 1).is_ok());
+    assert!(PhysicalAddress::new(1 << 52).is_err());
+    assert!(PhysicalAddress::new(usize::MAX).is_err());
+    assert_eq!(PhysicalAddress::new_canonical((1 << 52) | 0x1000).value(), 0x1000);
+}
+
+#[test]
+fn virtual_address_new_canonical_check() {
+    assert!(VirtualAddress::new(0x1000).is_ok());
+    assert!(VirtualAddress::new(0xFFFF_FFFF_8000_0000).is_ok());
+    assert!(VirtualAddress::is_canonical(0x0000_7FFF_FFFF_FFFF));
+
+    let non_canonical = 0x0000_8000_0000_0000;
+    assert_eq!(VirtualAddress::new(non_canonical).is_err(), IS_CANONICAL_CHECK_NEEDED);
+    assert_eq!(VirtualAddress::is_canonical(non_canonical), !IS_CANONICAL_CHECK_NEEDED);
+}
+
+#[test]
+fn range_rev() {
+    let range = PageRange::new(page(3), page(7));
+    let mut forward: Vec<Page> = range.clone().into_iter().collect();
+    forward.reverse();
+    let reversed: Vec<Page> = range.clone().rev().collect();
+    assert_eq!(reversed, forward);
+    assert_eq!(reversed.first(), Some(&page(7)));
+    assert_eq!(reversed.last(), Some(&page(3)));
+    assert_eq!(range.rev().next_back(), Some(page(3)));
+
+    let frames: Vec<Frame> = FrameRange::new(frame(1), frame(3)).rev().collect();
+    assert_eq!(frames, [frame(3), frame(2), frame(1)]);
+    assert_eq!(FrameRange::empty().rev().count(), 0);
+}
+
+#[test]
+fn page_and_frame_max() {
+    assert_eq!(Frame::max().number, MAX_FRAME_NUMBER);
+    assert_eq!(Frame::max() + 1, Frame::max());
+    assert_eq!(Frame::containing_address(Frame::max().start_address()), Frame::max());
+    assert_eq!(Frame::max().start_address().value(), PhysicalAddress::MAX.value() & !(PAGE_SIZE - 1));
+    assert_eq!(Page::max() + 1, Page::max());
+    assert_eq!(Page::max().checked_add(1), None);
+    // the `Ord::max()` method is still usable on instances
+    assert_eq!(frame(3).max(frame(5)), frame(5));
+}
+
+#[test]
+fn range_trim() {
+    let range = PageRange::new(page(10), page(19));
+    assert_eq!(range.clone().trim_start(0), range);
+    assert_eq!(range.clone().trim_start(3), PageRange::new(page(13), page(19)));
+    assert_eq!(range.clone().trim_end(3), PageRange::new(page(10), page(16)));
+    assert!(range.clone().trim_start(10).is_empty());
+    assert!(range.trim_end(25).is_empty());
+
+    let frames = FrameRange::new(frame(10), frame(19));
+    assert_eq!(frames.clone().trim_end(0), frames);
+    assert_eq!(frames.clone().trim_start(9), FrameRange::new(frame(19), frame(19)));
+    assert_eq!(frames.clone().trim_end(4).size_in_frames(), 6);
+    assert!(frames.trim_start(11).is_empty());
+}
+
+#[test]
+fn huge_page_start_address_alignment() {
+    let addr = VirtualAddress::new_canonical(0x4030_1234);
+    let huge = Page::<Size2MiB>::containing_address_sized(addr);
+    assert_eq!(huge.start_address().value() % Size2MiB::SIZE, 0);
+    assert_eq!(huge.start_address(), VirtualAddress::new_canonical(0x4020_0000));
+    assert!(huge.start_address() <= addr);
+    assert!(addr.value() - huge.start_address().value() < Size2MiB::SIZE);
+
+    // the default page size is still 4KiB
+    let normal: Page<Size4KiB> = Page::containing_address(addr);
+    assert_eq!(normal.start_address(), VirtualAddress::new_canonical(0x4030_1000));
+    assert_eq!(Page::<Size4KiB>::containing_address_sized(addr), normal);
+}
+
+#[test]
+fn physical_memory_area_frame_range_round_trip() {
+    let area = PhysicalMemoryArea::new(PhysicalAddress::new_canonical(0x10_0000), 0x8000, 1, 3);
+    let frames = area.frame_range();
+    assert_eq!(frames, FrameRange::new(frame(0x100), frame(0x107)));
+    let back = frames.as_physical_memory_area(area.typ, area.acpi);
+    assert_eq!(back.base_addr, area.base_addr);
+    assert_eq!(back.size_in_bytes, area.size_in_bytes);
+    assert_eq!((back.typ, back.acpi), (1, 3));
+
+    // an unaligned area is widened to the frames that cover it
+    let unaligned = PhysicalMemoryArea::new(PhysicalAddress::new_canonical(0x10_0800), 0x1000, 2, 0);
+    let widened = unaligned.frame_range().as_physical_memory_area(2, 0);
+    assert_eq!(widened.base_addr, PhysicalAddress::new_canonical(0x10_0000));
+    assert_eq!(widened.size_in_bytes, 0x2000);
+
+    let empty = FrameRange::empty().as_physical_memory_area(1, 0);
+    assert_eq!(empty.size_in_bytes, 0);
+    assert!(empty.frame_range().is_empty());
+}
+
+#[test]
+fn containing_address_checked() {
+    let addr = VirtualAddress::new_canonical(0x4030_1234);
+    assert_eq!(Page::containing_address_checked(addr), Ok(Page::containing_address(addr)));
+    let paddr = PhysicalAddress::new_canonical(0x4030_1234);
+    assert_eq!(Frame::containing_address_checked(paddr.value()), Ok(Frame::containing_address(paddr)));
+
+    // The current config spans the full width of a `usize`,
+    // so even the highest address falls within the max page.
+    let highest = VirtualAddress::new_canonical(usize::MAX);
+    assert_eq!(Page::containing_address_checked(highest), Ok(Page::max()));
+
+    // Physical addresses are limited to `MAX_PHYSICAL_ADDRESS_BITS`.
+    let highest_phys = (1 << MAX_PHYSICAL_ADDRESS_BITS) - 1;
+    assert_eq!(Frame::containing_address_checked(highest_phys), Ok(Frame::containing_address(PhysicalAddress::MAX)));
+    assert_eq!(Frame::containing_address_checked(highest_phys + 1), Err(MemoryStructError::FrameNumberTooLarge));
+    assert_eq!(Frame::containing_address_checked(usize::MAX), Err(MemoryStructError::FrameNumberTooLarge));
+}
+
+#[test]
+fn memory_map_queries() {
+    let area = |base: usize, size: usize, typ: u32| PhysicalMemoryArea::new(PhysicalAddress::new_canonical(base), size, typ, 0);
+    let areas = [
+        area(0x0, 0x9_F000, USABLE_MEMORY_AREA_TYPE),
+        area(0x9_F000, 0x6_1000, 2),
+        area(0x10_0000, 0, USABLE_MEMORY_AREA_TYPE),
+        area(0x10_0000, 0x7EE_0000, USABLE_MEMORY_AREA_TYPE),
+        area(0x7FE_0000, 0x2_0000, 3),
+        area(0x1_0000_0000, 0x7EE_0000, USABLE_MEMORY_AREA_TYPE),
+    ];
+    let map = MemoryMap::new(&areas);
+    let usable: Vec<usize> = map.usable_regions().map(|a| a.base_addr.value()).collect();
+    assert_eq!(usable, vec![0x0, 0x10_0000, 0x1_0000_0000]);
+    assert_eq!(map.total_usable_bytes(), 0x9_F000 + 0x7EE_0000 * 2);
+    assert_eq!(map.largest_usable_region().unwrap().base_addr.value(), 0x10_0000);
+
+    let reserved_only = [area(0x0, 0x1000, 2), area(0x1000, 0, USABLE_MEMORY_AREA_TYPE)];
+    let map = MemoryMap::new(&reserved_only);
+    assert_eq!(map.usable_regions().count(), 0);
+    assert_eq!(map.total_usable_bytes(), 0);
+    assert!(map.largest_usable_region().is_none());
+}
+
+#[test]
+fn range_overlap_size() {
+    let range = PageRange::new(page(10), page(19));
+    assert_eq!(range.overlap_size(&PageRange::new(page(15), page(30))), 5);
+    assert_eq!(range.overlap_size(&PageRange::new(page(0), page(10))), 1);
+    assert_eq!(range.overlap_size(&PageRange::new(page(12), page(13))), 2);
+    assert_eq!(range.overlap_size(&range), 10);
+    assert_eq!(range.overlap_size(&PageRange::new(page(20), page(25))), 0);
+    assert_eq!(range.overlap_size(&PageRange::empty()), 0);
+
+    let frames = FrameRange::new(frame(100), frame(199));
+    assert_eq!(frames.overlap_size(&FrameRange::new(frame(50), frame(149))), 50);
+    assert_eq!(FrameRange::new(frame(0), frame(99)).overlap_size(&frames), 0);
+}
+
+#[test]
+fn frame_range_zip_pages() {
+    let frames = FrameRange::new(frame(0x100), frame(0x103));
+    let pairs: Vec<(Page, Frame)> = frames.zip_pages(&PageRange::new(page(0x500), page(0x503))).collect();
+    assert_eq!(pairs, vec![
+        (page(0x500), frame(0x100)),
+        (page(0x501), frame(0x101)),
+        (page(0x502), frame(0x102)),
+        (page(0x503), frame(0x103)),
+    ]);
+    assert_eq!(frames.zip_pages(&PageRange::new(page(0), page(1))).count(), 2);
+    assert_eq!(frames.zip_pages(&PageRange::empty()).count(), 0);
+}
+
+#[test]
+fn address_checked_offset() {
+    let virt = VirtualAddress::new_canonical(0x1000);
+    assert_eq!(virt.checked_offset(0x234), Some(VirtualAddress::new_canonical(0x1234)));
+    assert_eq!(virt.checked_offset(-0x1000), Some(VirtualAddress::zero()));
+    assert_eq!(virt.checked_offset(0), Some(virt));
+    assert_eq!(virt.checked_offset(-0x1001), None);
+    assert_eq!(VirtualAddress::new_canonical(usize::MAX).checked_offset(1), None);
+    assert_eq!(VirtualAddress::zero().checked_offset(isize::MIN), None);
+
+    let phys = PhysicalAddress::new_canonical(0x2000);
+    assert_eq!(phys.checked_offset(-0x800), Some(PhysicalAddress::new_canonical(0x1800)));
+    assert_eq!(phys.checked_offset(-0x2001), None);
+    // exceeds the maximum number of physical address bits
+    assert_eq!(phys.checked_offset(isize::MAX), None);
+}
+
+#[test]
+fn range_shifted_by_bytes() {
+    let range = PageRange::new(page(10), page(19));
+    assert_eq!(range.shifted_by_bytes(0), Ok(range.clone()));
+    assert_eq!(range.shifted_by_bytes(3 * PAGE_SIZE), Ok(PageRange::new(page(13), page(22))));
+    assert!(range.shifted_by_bytes(PAGE_SIZE + 1).is_err());
+    assert!(range.shifted_by_bytes(PAGE_SIZE / 2).is_err());
+    assert!(PageRange::new(page(0), Page::max()).shifted_by_bytes(PAGE_SIZE).is_err());
+    assert!(PageRange::empty().shifted_by_bytes(PAGE_SIZE).unwrap().is_empty());
+
+    let frames = FrameRange::new(frame(0x100), frame(0x1FF));
+    assert_eq!(frames.shifted_by_bytes(0x10_0000), Ok(FrameRange::new(frame(0x200), frame(0x2FF))));
+    assert!(frames.shifted_by_bytes(0x800).is_err());
+}
+
+/// Creates the bounds of a section that is identity-mapped at `start` and spans `size_in_bytes`.
+fn section_bounds(start: usize, size_in_bytes: usize, flags: EntryFlags) -> SectionMemoryBounds {
+    SectionMemoryBounds {
+        start: (VirtualAddress::new_canonical(start), PhysicalAddress::new_canonical(start)),
+        end: (VirtualAddress::new_canonical(start + size_in_bytes), PhysicalAddress::new_canonical(start + size_in_bytes)),
+        flags,
+    }
+}
+
+#[test]
+fn aggregated_section_bounds_iter() {
+    let mut bounds = AggregatedSectionMemoryBounds {
+        text:   section_bounds(0x10_0000, 0x4000, EntryFlags::PRESENT),
+        rodata: section_bounds(0x10_4000, 0x2000, EntryFlags::PRESENT | EntryFlags::NO_EXECUTE),
+        data:   section_bounds(0x10_6000, 0x3000, EntryFlags::PRESENT | EntryFlags::WRITABLE | EntryFlags::NO_EXECUTE),
+        stack:  section_bounds(0x20_0000, 0x8000, EntryFlags::PRESENT | EntryFlags::WRITABLE | EntryFlags::NO_EXECUTE),
+    };
+    let starts: Vec<usize> = bounds.iter().map(|b| b.start.0.value()).collect();
+    assert_eq!(starts, vec![0x10_0000, 0x10_4000, 0x10_6000, 0x20_0000]);
+
+    for b in bounds.iter_mut() {
+        b.flags |= EntryFlags::ACCESSED;
+    }
+    assert_eq!(bounds.iter().count(), 4);
+    assert!(bounds.iter().all(|b| b.flags.contains(EntryFlags::ACCESSED | EntryFlags::PRESENT)));
+}
+
+#[test]
+fn section_bounds_page_and_frame_range() {
+    let single = section_bounds(0x10_0000, PAGE_SIZE, EntryFlags::PRESENT);
+    assert_eq!(single.page_range(), PageRange::new(page(0x100), page(0x100)));
+    assert_eq!(single.frame_range(), FrameRange::new(frame(0x100), frame(0x100)));
+
+    let multi = section_bounds(0x10_0000, 3 * PAGE_SIZE + 0x10, EntryFlags::PRESENT);
+    assert_eq!(multi.page_range(), PageRange::new(page(0x100), page(0x103)));
+    assert_eq!(multi.frame_range().size_in_frames(), 4);
+
+    let offset = SectionMemoryBounds {
+        start: (VirtualAddress::new_canonical(0x40_2000), PhysicalAddress::new_canonical(0x2000)),
+        end: (VirtualAddress::new_canonical(0x40_4000), PhysicalAddress::new_canonical(0x4000)),
+        flags: EntryFlags::PRESENT,
+    };
+    assert_eq!(offset.page_range(), PageRange::new(page(0x402), page(0x403)));
+    assert_eq!(offset.frame_range(), FrameRange::new(frame(2), frame(3)));
+
+    let empty = section_bounds(0x10_0000, 0, EntryFlags::PRESENT);
+    assert!(empty.page_range().is_empty());
+    assert!(empty.frame_range().is_empty());
+}
+
+#[test]
+fn section_bounds_size_and_validate() {
+    let bounds = section_bounds(0x10_0000, 0x3000, EntryFlags::PRESENT);
+    assert_eq!(bounds.size_in_bytes(), 0x3000);
+    assert_eq!(bounds.validate(), Ok(()));
+
+    let mismatched = SectionMemoryBounds {
+        start: (VirtualAddress::new_canonical(0x40_0000), PhysicalAddress::new_canonical(0x1000)),
+        end: (VirtualAddress::new_canonical(0x40_3000), PhysicalAddress::new_canonical(0x2000)),
+        flags: EntryFlags::PRESENT,
+    };
+    assert_eq!(mismatched.size_in_bytes(), 0x3000);
+    assert_eq!(mismatched.validate(), Err(MemoryStructError::MismatchedSpans));
+
+    let inverted = SectionMemoryBounds {
+        start: bounds.end,
+        end: bounds.start,
+        flags: EntryFlags::PRESENT,
+    };
+    assert_eq!(inverted.size_in_bytes(), 0);
+    assert_eq!(inverted.validate(), Err(MemoryStructError::InvalidVirtualRange));
+
+    let inverted_phys = SectionMemoryBounds {
+        start: (bounds.start.0, bounds.end.1),
+        end: (bounds.end.0, bounds.start.1),
+        flags: EntryFlags::PRESENT,
+    };
+    let err = inverted_phys.validate().unwrap_err();
+    assert_eq!(err, MemoryStructError::InvalidPhysicalRange);
+    assert_eq!(err.as_str(), "start physical address is after end physical address");
+}
+
+#[test]
+fn address_display_padding() {
+    let virt = VirtualAddress::new_canonical(0x1234);
+    assert_eq!(format!("{}", virt), "v0x1234");
+    assert_eq!(format!("{}", virt), format!("{:?}", virt));
+    assert_eq!(format!("{:>10}", virt), "   v0x1234");
+    assert_eq!(format!("{:<10}|", virt), "v0x1234   |");
+    assert_eq!(format!("{:*^11}", virt), "**v0x1234**");
+    // a width smaller than the address does not truncate it
+    assert_eq!(format!("{:3}", virt), "v0x1234");
+
+    let phys = PhysicalAddress::new_canonical(0xFEE0_0000);
+    assert_eq!(format!("{:>13}", phys), "  p0xFEE00000");
+    let max = VirtualAddress::new_canonical(usize::MAX);
+    assert_eq!(format!("{:>20}", max), " v0xFFFFFFFFFFFFFFFF");
+}
+
+#[test]
+fn page_and_frame_display() {
+    assert_eq!(format!("{}", frame(1)), "p0x1000");
+    assert_eq!(format!("{:?}", frame(1)), "Frame(p0x1000)");
+    assert_eq!(format!("{}", page(0x10)), "v0x10000");
+    assert_eq!(format!("{:?}", page(0x10)), "Page(v0x10000)");
+    assert_ne!(format!("{}", page(0x10)), format!("{:?}", page(0x10)));
+    assert_eq!(format!("{:>9}", frame(1)), "  p0x1000");
+}
+
+#[test]
+fn range_nth_page_and_frame() {
+    let range = PageRange::new(page(10), page(19));
+    assert_eq!(range.nth_page(0), Some(page(10)));
+    assert_eq!(range.nth_page(9), Some(page(19)));
+    assert_eq!(range.nth_page(10), None);
+    assert_eq!(range.nth_page(usize::MAX), None);
+    assert_eq!(PageRange::empty().nth_page(0), None);
+
+    let frames = FrameRange::new(frame(5), frame(5));
+    assert_eq!(frames.nth_frame(0), Some(frame(5)));
+    assert_eq!(frames.nth_frame(1), None);
+}
+
+#[test]
+fn physical_memory_area_end_and_last_frame() {
+    let base = PhysicalAddress::new_canonical(0x5000);
+    let one_byte = PhysicalMemoryArea::new(base, 1, 1, 0);
+    assert_eq!(one_byte.end_address(), PhysicalAddress::new_canonical(0x5001));
+    assert_eq!(one_byte.last_frame(), Some(frame(5)));
+
+    let one_page = PhysicalMemoryArea::new(base, PAGE_SIZE, 1, 0);
+    assert_eq!(one_page.end_address(), PhysicalAddress::new_canonical(0x6000));
+    assert_eq!(one_page.last_frame(), Some(frame(5)));
+
+    let zero_size = PhysicalMemoryArea::new(base, 0, 1, 0);
+    assert_eq!(zero_size.end_address(), base);
+    assert_eq!(zero_size.last_frame(), None);
+}
+
+#[test]
+fn range_bounding() {
+    let low = PageRange::new(page(10), page(19));
+    let high = PageRange::new(page(30), page(39));
+    assert_eq!(low.bounding(&high), PageRange::new(page(10), page(39)));
+    assert_eq!(high.bounding(&low), PageRange::new(page(10), page(39)));
+    assert_eq!(low.bounding(&PageRange::new(page(12), page(14))), low);
+    assert_eq!(low.bounding(&PageRange::empty()), low);
+    assert_eq!(PageRange::empty().bounding(&high), high);
+    assert!(PageRange::empty().bounding(&PageRange::empty()).is_empty());
+
+    let frames = FrameRange::new(frame(100), frame(100));
+    assert_eq!(frames.bounding(&FrameRange::new(frame(0), frame(1))), FrameRange::new(frame(0), frame(100)));
+}
+
+#[test]
+fn range_gap_to() {
+    let frames = FrameRange::new(frame(10), frame(19));
+    assert_eq!(frames.gap_to(&FrameRange::new(frame(20), frame(25))), Some(0));
+    assert_eq!(frames.gap_to(&FrameRange::new(frame(21), frame(25))), Some(1));
+    assert_eq!(FrameRange::new(frame(0), frame(8)).gap_to(&frames), Some(1));
+    assert_eq!(frames.gap_to(&FrameRange::new(frame(19), frame(25))), None);
+    assert_eq!(frames.gap_to(&FrameRange::new(frame(12), frame(13))), None);
+    assert_eq!(frames.gap_to(&FrameRange::empty()), None);
+
+    let range = PageRange::new(page(100), page(199));
+    assert_eq!(range.gap_to(&PageRange::new(page(0), page(49))), Some(50));
+    assert_eq!(range.gap_to(&range), None);
+}
+
+#[test]
+fn range_try_from_contiguous() {
+    assert_eq!(FrameRange::try_from_frames(vec![frame(3), frame(4), frame(5)]), Ok(FrameRange::new(frame(3), frame(5))));
+    assert_eq!(FrameRange::try_from_frames(FrameRange::new(frame(8), frame(12))), Ok(FrameRange::new(frame(8), frame(12))));
+    assert!(FrameRange::try_from_frames(vec![frame(3), frame(5)]).is_err());
+    assert!(FrameRange::try_from_frames(vec![frame(3), frame(3)]).is_err());
+    assert!(FrameRange::try_from_frames(Vec::new()).unwrap().is_empty());
+
+    assert_eq!(PageRange::try_from_pages(vec![page(7)]), Ok(PageRange::new(page(7), page(7))));
+    assert!(PageRange::try_from_pages(vec![page(2), page(1)]).is_err());
+    assert!(PageRange::try_from_pages(Vec::new()).unwrap().is_empty());
+}
+
+#[test]
+fn range_aligned_subrange() {
+    let aligned = PageRange::new(page(512), page(1535));
+    assert_eq!(aligned.aligned_subrange(512), aligned);
+    let partial = PageRange::new(page(100), page(1600));
+    assert_eq!(partial.aligned_subrange(512), PageRange::new(page(512), page(1535)));
+    assert!(PageRange::new(page(100), page(1000)).aligned_subrange(512).is_empty());
+    assert!(PageRange::new(page(513), page(1023)).aligned_subrange(512).is_empty());
+    assert!(aligned.aligned_subrange(0).is_empty());
+
+    let frames = FrameRange::new(frame(3), frame(17));
+    assert_eq!(frames.aligned_subrange(4), FrameRange::new(frame(4), frame(15)));
+    assert_eq!(frames.aligned_subrange(1), frames);
+    assert!(frames.aligned_subrange(16).is_empty());
+}
+
+#[test]
+fn aggregated_section_bounds_into_iter() {
+    let bounds = AggregatedSectionMemoryBounds {
+        text:   section_bounds(0x10_0000, 0x4000, EntryFlags::PRESENT),
+        rodata: section_bounds(0x10_4000, 0x2000, EntryFlags::PRESENT),
+        data:   section_bounds(0x10_6000, 0x3000, EntryFlags::PRESENT),
+        stack:  section_bounds(0x20_0000, 0x8000, EntryFlags::PRESENT),
+    };
+    let sections: Vec<SectionMemoryBounds> = bounds.into_iter().collect();
+    assert_eq!(sections.len(), 4);
+    let sizes: Vec<usize> = sections.iter().map(|b| b.size_in_bytes()).collect();
+    assert_eq!(sizes, vec![0x4000, 0x2000, 0x3000, 0x8000]);
+}
+
+#[test]
+fn address_wrapping_arithmetic() {
+    let max = VirtualAddress::new_canonical(usize::MAX);
+    assert_eq!(max.wrapping_add(1), VirtualAddress::zero());
+    assert_eq!(max.wrapping_add(0x1001), VirtualAddress::new_canonical(0x1000));
+    assert_eq!(max + 1, max);
+    assert_eq!(VirtualAddress::zero().wrapping_sub(1), max);
+    assert_eq!(VirtualAddress::new_canonical(0x2000).wrapping_sub(0x1000), VirtualAddress::new_canonical(0x1000));
+
+    // the highest physical address wraps within the bits allowed by `MAX_PHYSICAL_ADDRESS_BITS`
+    assert_eq!(PhysicalAddress::new_canonical(usize::MAX).wrapping_add(1), PhysicalAddress::zero());
+    let phys = PhysicalAddress::new_canonical(0x1000);
+    assert_eq!(phys.wrapping_sub(0x1001).wrapping_add(0x1001), phys);
+    assert_eq!(PhysicalAddress::zero().wrapping_sub(0x1000).wrapping_add(0x2000), phys);
+}
+
+#[test]
+fn page_and_frame_compare_with_number() {
+    assert_eq!(frame(5), 5);
+    assert_ne!(frame(5), 6);
+    assert!(frame(5) < 6);
+    assert!(frame(5) >= 5);
+    assert!(frame(5) > 4);
+
+    assert_eq!(page(100), 100);
+    assert!(page(99) < 100);
+    assert!(page(100) > 99);
+    assert!(Page::<Size2MiB>::containing_address_sized(VirtualAddress::new_canonical(0x40_0000)) == 2);
+}
+
+#[test]
+fn range_windows() {
+    let pairs: Vec<(Page, Page)> = PageRange::new(page(10), page(13)).windows().collect();
+    assert_eq!(pairs, vec![(page(10), page(11)), (page(11), page(12)), (page(12), page(13))]);
+    assert_eq!(PageRange::new(page(10), page(10)).windows().count(), 0);
+    assert_eq!(PageRange::empty().windows().count(), 0);
+
+    let frame_pairs: Vec<(Frame, Frame)> = FrameRange::new(frame(0), frame(2)).windows().collect();
+    assert_eq!(frame_pairs, vec![(frame(0), frame(1)), (frame(1), frame(2))]);
+}
+
+#[test]
+fn structured_errors() {
+    assert_eq!(PhysicalAddress::new(1 << 60), Err(MemoryStructError::PhysicalAddressTooLarge));
+    assert_eq!(VirtualAddress::new(0x0000_8000_0000_0000), Err(MemoryStructError::NonCanonicalVirtualAddress));
+    match PageRange::new(page(0), page(1)).shifted_by_bytes(0x10) {
+        Err(MemoryStructError::NotPageAligned) => { }
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert_eq!(FrameRange::try_from_frames(vec![frame(1), frame(3)]), Err(MemoryStructError::NotContiguous));
+
+    // the messages are unchanged from when these errors were plain strings
+    assert_eq!(format!("{}", MemoryStructError::PhysicalAddressTooLarge), "PhysicalAddress bits 52-63 must be zero");
+    let msg: &'static str = MemoryStructError::NonCanonicalVirtualAddress.into();
+    assert_eq!(msg, "VirtualAddress bits 48-63 must be a sign-extension of bit 47");
+    // a generic error's message does not name any one caller
+    assert_eq!(MemoryStructError::AddressOverflow.as_str(), "address arithmetic overflowed");
+}
+
+#[test]
+fn page_and_frame_table_indices() {
+    use heapless::consts::U4;
+    let p = Page::containing_address(VirtualAddress::new_canonical(0xFFFF_8123_4567_8000));
+    let indices = p.table_indices::<U4>(&[9, 9, 9, 9]).unwrap();
+    assert_eq!(&indices[..], &[p.p4_index(), p.p3_index(), p.p2_index(), p.p1_index()][..]);
+
+    // number 0b101_0011_0110 split into widths of 3, 4, and 4 bits
+    let indices = frame(0b101_0011_0110).table_indices::<U4>(&[3, 4, 4]).unwrap();
+    assert_eq!(&indices[..], &[0b101, 0b0011, 0b0110][..]);
+    assert!(frame(1).table_indices::<U4>(&[2, 2, 2, 2, 2]).is_err());
+}
+
+#[test]
+fn range_contains_all() {
+    let frames = FrameRange::new(frame(10), frame(19));
+    assert!(frames.contains_all(&[frame(10), frame(15), frame(19)]));
+    assert!(!frames.contains_all(&[frame(10), frame(20), frame(15)]));
+    assert!(frames.contains_all(&[]));
+    assert!(FrameRange::empty().contains_all(&[]));
+
+    let range = PageRange::new(page(0), page(3));
+    assert!(range.contains_all(&[page(3), page(0)]));
+    assert!(!range.contains_all(&[page(4)]));
+    assert!(range.contains_all(&[]));
+}
+
+#[test]
+fn address_round_to_multiple() {
+    let virt = VirtualAddress::new_canonical(1000);
+    assert_eq!(virt.round_up_to_multiple(192), Some(VirtualAddress::new_canonical(1152)));
+    assert_eq!(virt.round_down_to_multiple(192), Some(VirtualAddress::new_canonical(960)));
+    assert_eq!(VirtualAddress::new_canonical(960).round_up_to_multiple(192), Some(VirtualAddress::new_canonical(960)));
+    assert_eq!(virt.round_up_to_multiple(1), Some(virt));
+    assert_eq!(virt.round_down_to_multiple(1), Some(virt));
+    assert_eq!(virt.round_up_to_multiple(0), None);
+    assert_eq!(virt.round_down_to_multiple(0), None);
+    // rounding up past the top of the address space fails rather than saturating to a non-multiple
+    assert_eq!(VirtualAddress::MAX.round_up_to_multiple(192), None);
+
+    // higher-half addresses stay in the higher half when rounded up...
+    let high = VirtualAddress::new_canonical(0xFFFF_8000_0000_0001);
+    let rounded = high.round_up_to_multiple(192).unwrap();
+    assert_eq!(rounded.value() % 192, 0);
+    assert!(rounded > high && rounded.value() - high.value() < 192);
+    // ...but fail if rounding crosses into the non-canonical hole between the two halves
+    let high_start = VirtualAddress::new_canonical(0xFFFF_8000_0000_0000);
+    assert_ne!(high_start.value() % 192, 0);
+    assert_eq!(high_start.round_down_to_multiple(192), None);
+    assert_eq!(VirtualAddress::new_canonical(0x7FFF_FFFF_FFFF).round_up_to_multiple(192), None);
+
+    let phys = PhysicalAddress::new_canonical(0x1001);
+    assert_eq!(phys.round_up_to_multiple(192), Some(PhysicalAddress::new_canonical(4224)));
+    assert_eq!(phys.round_down_to_multiple(192), Some(PhysicalAddress::new_canonical(4032)));
+    assert_eq!(phys.round_up_to_multiple(1), Some(phys));
+    assert_eq!(PhysicalAddress::MAX.round_up_to_multiple(192), None);
+    assert_eq!(PhysicalAddress::MAX.round_down_to_multiple(192).map(|a| a.value() % 192), Some(0));
+}
+
+#[test]
+fn range_enclosing_aligned() {
+    let range = PageRange::new(page(100), page(600));
+    let enclosing = range.enclosing_aligned(512);
+    assert_eq!(enclosing, PageRange::new(page(0), page(1023)));
+    assert!(enclosing.start().is_aligned_to(512));
+    assert!((*enclosing.end() + 1).is_aligned_to(512));
+    assert!(enclosing.contains(range.start()) && enclosing.contains(range.end()));
+
+    let aligned = PageRange::new(page(512), page(1023));
+    assert_eq!(aligned.enclosing_aligned(512), aligned);
+    assert_eq!(aligned.enclosing_aligned(512).aligned_subrange(512), aligned);
+    assert_eq!(PageRange::new(page(0), Page::max()).enclosing_aligned(512).end(), &Page::max());
+    assert_eq!(FrameRange::new(frame(0), Frame::max()).enclosing_aligned(512).end(), &Frame::max());
+
+    let frames = FrameRange::new(frame(5), frame(5));
+    assert_eq!(frames.enclosing_aligned(4), FrameRange::new(frame(4), frame(7)));
+    assert!(FrameRange::empty().enclosing_aligned(4).is_empty());
+}
+
+#[test]
+fn page_and_frame_step_by() {
+    let frames: Vec<Frame> = frame(10).step_by_frames(4, 3).collect();
+    assert_eq!(frames, vec![frame(10), frame(13), frame(16), frame(19)]);
+    assert_eq!(frame(10).step_by_frames(0, 3).count(), 0);
+    assert_eq!(Frame::max().step_by_frames(3, 1).count(), 1);
+
+    let pages: Vec<Page> = page(0).step_by_pages(3, 512).collect();
+    assert_eq!(pages, vec![page(0), page(512), page(1024)]);
+}
+
+#[test]
+fn virtual_address_as_ptr() {
+    let mut value: u64 = 0xDEAD_BEEF;
+    let addr = VirtualAddress::new(&value as *const u64 as usize).unwrap();
+    assert_eq!(addr.as_ptr::<u64>(), &value as *const u64);
+    assert_eq!(unsafe { *addr.as_ptr::<u64>() }, 0xDEAD_BEEF);
+
+    let addr = VirtualAddress::new(&mut value as *mut u64 as usize).unwrap();
+    unsafe { *addr.as_mut_ptr::<u64>() = 42; }
+    assert_eq!(value, 42);
+}
+
+#[test]
+fn physical_memory_area_sort() {
+    let area = |base: usize, size: usize| PhysicalMemoryArea::new(PhysicalAddress::new_canonical(base), size, 1, 0);
+    let mut areas = [area(0x10_0000, 0x1000), area(0x0, 0x9_F000), area(0x10_0000, 0x800), area(0x7FE_0000, 0x2_0000)];
+    areas.sort();
+    let bases: Vec<usize> = areas.iter().map(|a| a.base_addr.value()).collect();
+    assert_eq!(bases, vec![0x0, 0x10_0000, 0x10_0000, 0x7FE_0000]);
+    assert_eq!(areas[1], area(0x10_0000, 0x800));
+    assert_ne!(areas[1], areas[2]);
+}
+
+#[test]
+fn page_number_from_address_matches_division() {
+    assert_eq!(1 << PAGE_SHIFT, PAGE_SIZE);
+    for &addr in &[0, 1, PAGE_SIZE - 1, PAGE_SIZE, 0x1234_5678, 0xFFFF_8000_0000_0000, usize::MAX] {
+        let virt = VirtualAddress::new_canonical(addr);
+        assert_eq!(Page::number_from_address(virt), virt.value() / PAGE_SIZE);
+        assert_eq!(Page::containing_address(virt), page(virt.value() / PAGE_SIZE));
+        let phys = PhysicalAddress::new_canonical(addr);
+        assert_eq!(Frame::containing_address(phys), frame(phys.value() / PAGE_SIZE));
+    }
+}
+
+#[test]
+fn range_bounds() {
+    fn pages_within<R: RangeBounds<Page>>(bounds: R, candidates: &[Page]) -> usize {
+        candidates.iter().filter(|&p| bounds.contains(p)).count()
+    }
+    let candidates = [page(0), page(5), page(9), page(10), page(11)];
+    let range = PageRange::new(page(5), page(10));
+    assert_eq!(range.start_bound(), Bound::Included(&page(5)));
+    assert_eq!(range.end_bound(), Bound::Included(&page(10)));
+    assert_eq!(pages_within(range, &candidates), 3);
+    assert_eq!(pages_within(PageRange::empty(), &candidates), 0);
+
+    let frames = FrameRange::new(frame(1), frame(2));
+    assert!(RangeBounds::contains(&frames, &frame(2)));
+    assert!(!RangeBounds::contains(&frames, &frame(3)));
+}
+
+#[test]
+fn address_pages_and_frames_between() {
+    let v = VirtualAddress::new_canonical;
+    assert_eq!(v(0x1000).pages_between(v(0x1FFF)), 1);
+    assert_eq!(v(0x1234).pages_between(v(0x1234)), 1);
+    assert_eq!(v(0x1FFF).pages_between(v(0x3000)), 3);
+    assert_eq!(v(0x3000).pages_between(v(0x1FFF)), 3);
+
+    let p = PhysicalAddress::new_canonical;
+    assert_eq!(p(0x5000).frames_between(p(0x5800)), 1);
+    assert_eq!(p(0x5800).frames_between(p(0x7000)), 3);
+}
+
+#[test]
+fn counted_frame_range() {
+    let frames = FrameRange::new(frame(10), frame(19));
+    let counted = frames.clone().with_cached_len();
+    assert_eq!(counted.len(), frames.size_in_frames());
+    assert_eq!(counted.len(), 10);
+    assert_eq!(counted.start_address(), frames.start_address());
+    assert!(!counted.is_empty());
+    assert_eq!(counted.into_inner(), frames);
+
+    let empty = FrameRange::empty().with_cached_len();
+    assert_eq!(empty.len(), 0);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn identity_page_and_frame() {
+    assert_eq!(frame(0x123).to_identity_page(), page(0x123));
+    assert_eq!(page(0x456).to_identity_frame(), frame(0x456));
+    assert_eq!(frame(7).to_identity_page().to_identity_frame(), frame(7));
+    let addr = PhysicalAddress::new_canonical(0x8_1000);
+    assert_eq!(
+        Frame::containing_address(addr).to_identity_page().start_address().value(),
+        addr.value(),
+    );
+}
+
+#[test]
+fn range_filter_aligned() {
+    let pages: Vec<Page> = PageRange::new(page(16), page(31)).filter_aligned(4).collect();
+    assert_eq!(pages, vec![page(16), page(20), page(24), page(28)]);
+    assert_eq!(PageRange::new(page(1), page(3)).filter_aligned(4).count(), 0);
+
+    let frames: Vec<Frame> = FrameRange::new(frame(3), frame(10)).filter_aligned(3).collect();
+    assert_eq!(frames, vec![frame(3), frame(6), frame(9)]);
+}
+
+#[test]
+fn range_index_at() {
+    let frames = FrameRange::new(frame(10), frame(19));
+    assert_eq!(frames.frame_at(0), frame(10));
+    assert_eq!(frames.frame_at(9), frame(19));
+    assert_eq!(PageRange::new(page(3), page(4)).page_at(1), page(4));
+}
+
+#[test]
+#[should_panic]
+fn range_index_at_out_of_bounds() {
+    FrameRange::new(frame(10), frame(19)).frame_at(10);
+}
+
+#[test]
+fn physical_memory_area_intersect() {
+    let area = PhysicalMemoryArea::new(PhysicalAddress::new_canonical(0x10_0000), 0x10_0000, 1, 0);
+    let low_end = FrameRange::new(frame(0xF0), frame(0x10F));
+    assert_eq!(area.intersect(&low_end), FrameRange::new(frame(0x100), frame(0x10F)));
+    assert_eq!(area.intersect(&FrameRange::new(frame(0x120), frame(0x12F))), FrameRange::new(frame(0x120), frame(0x12F)));
+    assert!(area.intersect(&FrameRange::new(frame(0x200), frame(0x2FF))).is_empty());
+    assert!(area.intersect(&FrameRange::new(frame(0), frame(0xFF))).is_empty());
+    assert!(PhysicalMemoryArea::new(PhysicalAddress::zero(), 0, 1, 0).intersect(&low_end).is_empty());
+}
+
+#[test]
+fn address_checked_align_up() {
+    let virt = VirtualAddress::new_canonical(0x1001);
+    assert_eq!(virt.checked_align_up(PAGE_SIZE), Some(VirtualAddress::new_canonical(0x2000)));
+    assert_eq!(VirtualAddress::new_canonical(0x2000).checked_align_up(PAGE_SIZE), Some(VirtualAddress::new_canonical(0x2000)));
+    assert_eq!(virt.checked_align_up(1), Some(virt));
+    assert_eq!(VirtualAddress::new_canonical(usize::MAX - 0x10).checked_align_up(PAGE_SIZE), None);
+
+    let phys = PhysicalAddress::new_canonical(0x20_0001);
+    assert_eq!(phys.checked_align_up(0x20_0000), Some(PhysicalAddress::new_canonical(0x40_0000)));
+    // rounds up past the maximum number of physical address bits
+    assert_eq!(PhysicalAddress::new_canonical(usize::MAX).checked_align_up(PAGE_SIZE), None);
+}
+
+#[test]
+fn range_iter_addresses_rev() {
+    let frames = FrameRange::new(frame(3), frame(6));
+    let mut forward: Vec<PhysicalAddress> = frames.clone().into_iter().map(|f| f.start_address()).collect();
+    forward.reverse();
+    assert_eq!(frames.iter_addresses_rev().collect::<Vec<_>>(), forward);
+    assert_eq!(frames.iter_addresses_rev().next(), Some(PhysicalAddress::new_canonical(0x6000)));
+
+    let pages = PageRange::new(page(0x10), page(0x11));
+    let addrs: Vec<VirtualAddress> = pages.iter_addresses_rev().collect();
+    assert_eq!(addrs, vec![VirtualAddress::new_canonical(0x11000), VirtualAddress::new_canonical(0x10000)]);
+    assert_eq!(PageRange::empty().iter_addresses_rev().count(), 0);
+}
+
+#[test]
+fn page_and_frame_strict_add() {
+    assert_eq!(frame(5).strict_add(3), frame(8));
+    assert_eq!(page(5).strict_add(0), page(5));
+    assert_eq!(Page::max().strict_add(0), Page::max());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn page_and_frame_strict_add_overflow() {
+    Frame::max().strict_add(1);
+}
+
+#[test]
+fn aggregated_section_bounds_bounding_page_range() {
+    let bounds = AggregatedSectionMemoryBounds {
+        text:   section_bounds(0x10_0000, 0x4000, EntryFlags::PRESENT),
+        rodata: section_bounds(0x10_4000, 0x2000, EntryFlags::PRESENT),
+        data:   section_bounds(0x10_8000, 0x3000, EntryFlags::PRESENT),
+        stack:  section_bounds(0x20_0000, 0x8000, EntryFlags::PRESENT),
+    };
+    let image = bounds.bounding_page_range(false);
+    assert_eq!(image, PageRange::new(page(0x100), page(0x10A)));
+    let with_stack = bounds.bounding_page_range(true);
+    assert_eq!(with_stack, PageRange::new(page(0x100), page(0x207)));
+    for section in bounds.iter() {
+        assert_eq!(with_stack.overlap_size(&section.page_range()), section.page_range().size_in_pages());
+    }
+    assert_eq!(image.overlap_size(&bounds.stack.page_range()), 0);
+}
+
+#[test]
+fn address_is_in_range() {
+    let pages = PageRange::new(page(1), page(2));
+    for &addr in &[0x0FFF, 0x1000, 0x2FFF, 0x3000, 0x1800] {
+        let virt = VirtualAddress::new_canonical(addr);
+        assert_eq!(virt.is_in(&pages), pages.contains_virt_addr(virt));
+    }
+    assert!(VirtualAddress::new_canonical(0x2FFF).is_in(&pages));
+    assert!(!VirtualAddress::new_canonical(0x3000).is_in(&pages));
+
+    let frames = FrameRange::new(frame(1), frame(1));
+    for &addr in &[0x0FFF, 0x1000, 0x1FFF, 0x2000] {
+        let phys = PhysicalAddress::new_canonical(addr);
+        assert_eq!(phys.is_in(&frames), frames.contains_phys_addr(phys));
+    }
+    assert!(PhysicalAddress::new_canonical(0x1000).is_in(&frames));
+    assert!(!PhysicalAddress::new_canonical(0x0FFF).is_in(&frames));
+}
+
+#[test]
+fn section_bounds_mapping_flags() {
+    let text = section_bounds(0x10_0000, 0x4000, EntryFlags::PRESENT);
+    let flags = text.mapping_flags();
+    assert_eq!(flags, MappingFlags::READABLE | MappingFlags::EXECUTABLE);
+    assert!(!flags.contains(MappingFlags::WRITABLE));
+
+    let data = section_bounds(0x10_6000, 0x3000, EntryFlags::PRESENT | EntryFlags::WRITABLE | EntryFlags::NO_EXECUTE);
+    assert_eq!(data.mapping_flags(), MappingFlags::READABLE | MappingFlags::WRITABLE);
+    assert!(MappingFlags::from_entry_flags(EntryFlags::WRITABLE).is_empty());
+}
+
+#[test]
+fn frame_range_partition_by_map() {
+    use heapless::consts::U8;
+    let area = |base: usize, size: usize, typ: u32| PhysicalMemoryArea::new(PhysicalAddress::new_canonical(base), size, typ, 0);
+    let map = [
+        area(0x0, 0x9_F000, USABLE_MEMORY_AREA_TYPE),
+        area(0x9_F000, 0x6_1000, 2),
+        area(0x10_0000, 0x10_0000, USABLE_MEMORY_AREA_TYPE),
+    ];
+    // crosses from usable memory into the reserved area and back into usable memory
+    let frames = FrameRange::new(frame(0x90), frame(0x10F));
+    let parts = frames.partition_by_map::<U8>(&map).unwrap();
+    assert_eq!(&parts[..], &[
+        (FrameRange::new(frame(0x90), frame(0x9E)), USABLE_MEMORY_AREA_TYPE),
+        (FrameRange::new(frame(0x9F), frame(0xFF)), 2),
+        (FrameRange::new(frame(0x100), frame(0x10F)), USABLE_MEMORY_AREA_TYPE),
+    ][..]);
+
+    // frames beyond the end of the map are omitted
+    let parts = FrameRange::new(frame(0x1F0), frame(0x2FF)).partition_by_map::<U8>(&map).unwrap();
+    assert_eq!(&parts[..], &[(FrameRange::new(frame(0x1F0), frame(0x1FF)), USABLE_MEMORY_AREA_TYPE)][..]);
+    assert!(frames.partition_by_map::<heapless::consts::U2>(&map).is_err());
+}
+
+#[test]
+fn frame_and_page_as_map_keys() {
+    use std::collections::HashMap;
+    let mut owners = HashMap::new();
+    owners.insert(frame(7), "heap");
+    owners.insert(frame(0x100), "stack");
+    assert_eq!(owners.get(&frame(7)), Some(&"heap"));
+    assert_eq!(owners.get(&frame(0x100)), Some(&"stack"));
+    assert_eq!(owners.get(&frame(8)), None);
+
+    let mut mapped = HashMap::new();
+    mapped.insert(page(3), frame(7));
+    assert_eq!(mapped.get(&Page::containing_address(VirtualAddress::new_canonical(0x3FFF))), Some(&frame(7)));
+}
+
+#[test]
+fn page_range_split_at_address() {
+    let range = PageRange::new(page(4), page(8));
+    let on_boundary = VirtualAddress::new_canonical(6 * PAGE_SIZE);
+    assert_eq!(range.clone().split_at_address(on_boundary), (Some(PageRange::new(page(4), page(5))), Some(PageRange::new(page(6), page(8)))));
+    // a mid-page address splits at the start of its containing page
+    let mid_page = VirtualAddress::new_canonical(6 * PAGE_SIZE + 0x123);
+    assert_eq!(range.clone().split_at_address(mid_page), (Some(PageRange::new(page(4), page(5))), Some(PageRange::new(page(6), page(8)))));
+    assert_eq!(range.clone().split_at_address(VirtualAddress::new_canonical(4 * PAGE_SIZE + 1)), (None, Some(range.clone())));
+    assert_eq!(range.clone().split_at_address(VirtualAddress::new_canonical(9 * PAGE_SIZE)), (Some(range), None));
+    assert_eq!(PageRange::empty().split_at_address(on_boundary), (None, None));
+}
+
+#[test]
+fn frame_range_split_at_address() {
+    let range = FrameRange::new(frame(4), frame(8));
+    assert_eq!(range.clone().split_at_address(PhysicalAddress::new_canonical(8 * PAGE_SIZE)), (Some(FrameRange::new(frame(4), frame(7))), Some(FrameRange::new(frame(8), frame(8)))));
+    assert_eq!(range.clone().split_at_address(PhysicalAddress::new_canonical(5 * PAGE_SIZE + 0xFFF)), (Some(FrameRange::new(frame(4), frame(4))), Some(FrameRange::new(frame(5), frame(8)))));
+    assert_eq!(range.clone().split_at_address(PhysicalAddress::new_canonical(0)), (None, Some(range)));
+}
+
+#[test]
+fn page_range_grow_by() {
+    let range = PageRange::new(page(10), page(19));
+    let grown = range.clone().grow_end_by(5);
+    assert_eq!(grown, PageRange::new(page(10), page(24)));
+    assert_eq!(grown.size_in_pages(), range.size_in_pages() + 5);
+    let grown = range.clone().grow_start_by(4);
+    assert_eq!(grown, PageRange::new(page(6), page(19)));
+    assert_eq!(grown.size_in_pages(), range.size_in_pages() + 4);
+
+    // clamped at the lowest and highest pages
+    assert_eq!(range.clone().grow_start_by(100), PageRange::new(page(0), page(19)));
+    assert_eq!(range.grow_end_by(usize::MAX), PageRange::new(page(10), Page::max()));
+    assert!(PageRange::empty().grow_end_by(3).is_empty());
+}
+
+#[test]
+fn frame_range_grow_by() {
+    let range = FrameRange::new(frame(10), frame(19));
+    let grown = range.clone().grow_end_by(1).grow_start_by(2);
+    assert_eq!(grown, FrameRange::new(frame(8), frame(20)));
+    assert_eq!(grown.size_in_frames(), range.size_in_frames() + 3);
+    assert_eq!(range.grow_end_by(MAX_FRAME_NUMBER), FrameRange::new(frame(10), Frame::max()));
+    assert!(FrameRange::empty().grow_start_by(3).is_empty());
+}
+
+#[test]
+fn frame_range_in_const() {
+    const RESERVED: FrameRange = FrameRange::new(Frame { number: 0x9F }, Frame { number: 0xFF });
+    const RESERVED_START: PhysicalAddress = RESERVED.start_address();
+    const RESERVED_FRAMES: usize = RESERVED.size_in_frames();
+    const NONE: FrameRange = FrameRange::empty();
+    assert_eq!(RESERVED_START, PhysicalAddress::new_canonical(0x9_F000));
+    assert_eq!(RESERVED_FRAMES, 0x61);
+    assert!(NONE.is_empty());
+}
+
+#[test]
+fn physical_memory_area_iter_page_starts() {
+    let area = PhysicalMemoryArea::new(PhysicalAddress::new_canonical(0x5000), 3 * PAGE_SIZE, 2, 0);
+    let starts: Vec<PhysicalAddress> = area.iter_page_starts().collect();
+    assert_eq!(starts, vec![
+        PhysicalAddress::new_canonical(0x5000),
+        PhysicalAddress::new_canonical(0x5000 + PAGE_SIZE),
+        PhysicalAddress::new_canonical(0x5000 + 2 * PAGE_SIZE),
+    ]);
+    let empty = PhysicalMemoryArea::new(PhysicalAddress::new_canonical(0x5000), 0, 2, 0);
+    assert_eq!(empty.iter_page_starts().count(), 0);
+}
+
+#[test]
+fn virtual_address_align_to_page() {
+    let aligned = VirtualAddress::new_canonical(3 * PAGE_SIZE);
+    assert_eq!(aligned.align_up_to_page(), Some(aligned));
+    assert_eq!(aligned.align_down_to_page(), aligned);
+    let mid_page = VirtualAddress::new_canonical(3 * PAGE_SIZE + 0x80);
+    assert_eq!(mid_page.align_up_to_page(), Some(VirtualAddress::new_canonical(4 * PAGE_SIZE)));
+    assert_eq!(mid_page.align_down_to_page(), aligned);
+    const ALIGNED_IN_CONST: Option<VirtualAddress> = VirtualAddress::new_canonical(1).align_up_to_page();
+    assert_eq!(ALIGNED_IN_CONST, Some(VirtualAddress::new_canonical(PAGE_SIZE)));
+
+    // the last page below the non-canonical hole cannot round up into the higher half
+    let below_hole = VirtualAddress::new_canonical(0x0000_7FFF_FFFF_F001);
+    assert_eq!(below_hole.align_up_to_page(), None);
+    assert_eq!(below_hole.align_down_to_page(), VirtualAddress::new_canonical(0x0000_7FFF_FFFF_F000));
+    let hole_start = VirtualAddress::new_canonical(0xFFFF_8000_0000_0000);
+    assert_eq!(hole_start.align_up_to_page(), Some(hole_start));
+    assert_eq!(VirtualAddress::new_canonical(0xFFFF_8000_0000_0001).align_up_to_page(), Some(hole_start + PAGE_SIZE));
+    assert_eq!(VirtualAddress::MAX.align_up_to_page(), None);
+}
+
+#[test]
+fn physical_address_align_to_frame() {
+    let aligned = PhysicalAddress::new_canonical(0x20_0000);
+    assert_eq!(aligned.align_up_to_frame(), Some(aligned));
+    assert_eq!(aligned.align_down_to_frame(), aligned);
+    let mid_frame = PhysicalAddress::new_canonical(0x20_0FFF);
+    assert_eq!(mid_frame.align_up_to_frame(), Some(PhysicalAddress::new_canonical(0x20_1000)));
+    assert_eq!(mid_frame.align_down_to_frame(), aligned);
+    // rounding up past the highest physical address must not wrap around to `0`
+    assert_eq!(PhysicalAddress::MAX.align_up_to_frame(), None);
+    assert_eq!(Frame::max().start_address().align_up_to_frame(), Some(Frame::max().start_address()));
+}
+
+#[test]
+fn page_range_map_to_frames() {
+    let pages = PageRange::new(page(0x10), page(0x12));
+    let pairs: Vec<(Page, Frame)> = pages.map_to_frames(|p| frame(p.number + 0x100)).collect();
+    assert_eq!(pairs, vec![(page(0x10), frame(0x110)), (page(0x11), frame(0x111)), (page(0x12), frame(0x112))]);
+    assert_eq!(PageRange::empty().map_to_frames(|_| frame(0)).count(), 0);
+}
+
+#[test]
+fn frame_range_try_new() {
+    assert_eq!(FrameRange::try_new(frame(1), frame(4)), Ok(FrameRange::new(frame(1), frame(4))));
+    assert_eq!(FrameRange::try_new(frame(1), Frame::max()), Ok(FrameRange::new(frame(1), Frame::max())));
+    assert_eq!(FrameRange::try_new(frame(1), frame(MAX_FRAME_NUMBER + 1)), Err(MemoryStructError::FrameNumberTooLarge));
+    assert_eq!(FrameRange::try_new(frame(MAX_FRAME_NUMBER + 1), frame(MAX_FRAME_NUMBER + 2)), Err(MemoryStructError::FrameNumberTooLarge));
+    // a frame beyond the highest physical address is rejected even though it is a valid page number
+    assert!(PhysicalAddress::new((MAX_FRAME_NUMBER + 1) * PAGE_SIZE).is_err());
+    assert_eq!(FrameRange::try_new(frame(1), frame(MAX_PAGE_NUMBER)), Err(MemoryStructError::FrameNumberTooLarge));
+}
+
+#[test]
+fn range_as_ref_inner() {
+    let frames = FrameRange::new(frame(2), frame(5));
+    let inner: &RangeInclusive<Frame> = frames.as_ref();
+    assert!(inner.contains(&frame(5)));
+    assert!(!inner.contains(&frame(6)));
+
+    let mut pages = PageRange::new(page(2), page(5));
+    assert!(pages.as_ref().contains(&page(2)));
+    assert_eq!(pages.as_mut().next(), Some(page(2)));
+    assert_eq!(pages.as_ref().start(), &page(3));
+}
+
+#[test]
+fn range_len_matches_size() {
+    for &(start, end) in &[(0, 0), (3, 9), (1, 0), (0x100, 0x2FF)] {
+        let pages = PageRange::new(page(start), page(end));
+        assert_eq!(pages.len(), pages.size_in_pages());
+        let frames = FrameRange::new(frame(start), frame(end));
+        assert_eq!(frames.len(), frames.size_in_frames());
+    }
+    assert_eq!(PageRange::new(page(3), page(9)).len(), 7);
+    assert_eq!(FrameRange::empty().len(), 0);
+}
+
+#[test]
+fn address_min_max_consts() {
+    assert_eq!(VirtualAddress::MIN, VirtualAddress::zero());
+    assert_eq!(VirtualAddress::MAX.value(), usize::MAX);
+    assert!(VirtualAddress::new(VirtualAddress::MAX.value()).is_ok());
+    assert_eq!(PhysicalAddress::MIN, PhysicalAddress::zero());
+    assert_eq!(PhysicalAddress::MAX.value(), (1 << MAX_PHYSICAL_ADDRESS_BITS) - 1);
+    assert!(PhysicalAddress::new(PhysicalAddress::MAX.value()).is_ok());
+
+    // clamping a computed end address to the address space
+    let end = PhysicalAddress::new_canonical(0x1000) + usize::MAX;
+    assert_eq!(core::cmp::min(end, PhysicalAddress::MAX), PhysicalAddress::MAX);
+    let near_end = VirtualAddress::new_canonical(0x8000);
+    assert_eq!(core::cmp::min(near_end, VirtualAddress::MAX), near_end);
+}
+
+#[test]
+fn frame_range_size_in_bytes() {
+    assert_eq!(FrameRange::new(frame(0x10), frame(0x13)).size_in_bytes(), 4 * PAGE_SIZE);
+    assert_eq!(FrameRange::empty().size_in_bytes(), 0);
+    assert_eq!(FrameRange::new(frame(0), Frame::max()).size_in_bytes(), 1 << MAX_PHYSICAL_ADDRESS_BITS);
+    assert_eq!(FrameRange::new(frame(0), frame(MAX_PAGE_NUMBER)).size_in_bytes(), usize::MAX);
+}
+
+#[test]
+fn page_range_size_in_bytes() {
+    assert_eq!(PageRange::new(page(0x10), page(0x13)).size_in_bytes(), 4 * PAGE_SIZE);
+    assert_eq!(PageRange::empty().size_in_bytes(), 0);
+    let full = PageRange::new(page(0), Page::max());
+    assert_eq!(full.size_in_bytes(), usize::MAX);
+    assert_eq!(AddressRange::size_in_bytes(&full), usize::MAX);
+    assert_eq!(full.address_at_offset(usize::MAX - 1), Some(VirtualAddress::new_canonical(usize::MAX - 1)));
+}
+
+#[test]
+fn frame_range_physical_memory_area_conversions() {
+    let frames = FrameRange::new(frame(0x10), frame(0x1F));
+    let area: PhysicalMemoryArea = frames.clone().into();
+    assert_eq!(area, PhysicalMemoryArea::new(PhysicalAddress::new_canonical(0x1_0000), 0x1_0000, USABLE_MEMORY_AREA_TYPE, 0));
+    assert_eq!(FrameRange::from(&area), frames);
+
+    // an area that isn't frame-aligned still round-trips to the frames covering it
+    let unaligned = PhysicalMemoryArea::new(PhysicalAddress::new_canonical(0x1_0800), 0x1000, 2, 0);
+    let covering = FrameRange::from(&unaligned);
+    assert_eq!(covering, FrameRange::new(frame(0x10), frame(0x11)));
+    assert_eq!(FrameRange::from(&PhysicalMemoryArea::from(covering.clone())), covering);
+}
+
+#[test]
+fn address_containing_page_and_frame() {
+    for &addr in &[0, 0xFFF, 0x1000, 0x1234_5678] {
+        let virt = VirtualAddress::new_canonical(addr);
+        assert_eq!(virt.containing_page(), Page::containing_address(virt));
+        let phys = PhysicalAddress::new_canonical(addr);
+        assert_eq!(phys.containing_frame(), Frame::containing_address(phys));
+    }
+    const PAGE: Page = VirtualAddress::new_canonical(0x5000).containing_page();
+    const FRAME: Frame = PhysicalAddress::new_canonical(0x5FFF).containing_frame();
+    assert_eq!(PAGE, page(5));
+    assert_eq!(FRAME, frame(5));
+}
+
+#[test]
+fn range_is_adjacent_to() {
+    let pages = PageRange::new(page(4), page(7));
+    assert!(pages.is_adjacent_to(&PageRange::new(page(8), page(9))));
+    assert!(PageRange::new(page(8), page(9)).is_adjacent_to(&pages));
+    assert!(PageRange::new(page(0), page(3)).is_adjacent_to(&pages));
+    assert!(!pages.is_adjacent_to(&PageRange::new(page(7), page(9))), "overlapping");
+    assert!(!pages.is_adjacent_to(&PageRange::new(page(9), page(10))), "gapped");
+    assert!(!pages.is_adjacent_to(&PageRange::empty()));
+
+    let frames = FrameRange::new(frame(4), frame(7));
+    assert!(frames.is_adjacent_to(&FrameRange::new(frame(8), frame(8))));
+    assert!(!frames.is_adjacent_to(&FrameRange::new(frame(5), frame(6))), "overlapping");
+    assert!(!frames.is_adjacent_to(&FrameRange::new(frame(0), frame(2))), "gapped");
+}
+
+#[test]
+fn page_and_frame_saturating_offset() {
+    assert_eq!(page(10).saturating_offset(5), page(15));
+    assert_eq!(page(10).saturating_offset(-5), page(5));
+    assert_eq!(page(10).saturating_offset(0), page(10));
+    assert_eq!(page(10).saturating_offset(-11), page(0));
+    assert_eq!(page(10).saturating_offset(isize::MIN), page(0));
+    assert_eq!(Page::max().saturating_offset(1), Page::max());
+
+    assert_eq!(frame(10).saturating_offset(-3), frame(7));
+    assert_eq!(frame(10).saturating_offset(3), frame(13));
+    assert_eq!(frame(2).saturating_offset(-3), frame(0));
+    assert_eq!(frame(MAX_FRAME_NUMBER - 1).saturating_offset(isize::MAX), Frame::max());
+}
+
+#[test]
+fn page_range_truncate_to_bytes() {
+    let range = PageRange::new(page(10), page(19));
+    assert_eq!(range.clone().truncate_to_bytes(3 * PAGE_SIZE), PageRange::new(page(10), page(12)));
+    // a budget between page boundaries is rounded down to whole pages
+    let truncated = range.clone().truncate_to_bytes(3 * PAGE_SIZE + 0x800);
+    assert_eq!(truncated, PageRange::new(page(10), page(12)));
+    assert!(truncated.size_in_bytes() <= 3 * PAGE_SIZE + 0x800);
+    assert_eq!(range.clone().truncate_to_bytes(10 * PAGE_SIZE), range);
+    assert_eq!(range.clone().truncate_to_bytes(usize::MAX), range);
+    assert!(range.truncate_to_bytes(PAGE_SIZE - 1).is_empty());
+}
+
+#[test]
+fn frame_range_truncate_to_bytes() {
+    let range = FrameRange::new(frame(10), frame(19));
+    assert_eq!(range.clone().truncate_to_bytes(PAGE_SIZE), FrameRange::new(frame(10), frame(10)));
+    assert_eq!(range.clone().truncate_to_bytes(5 * PAGE_SIZE - 1), FrameRange::new(frame(10), frame(13)));
+    assert_eq!(range.clone().truncate_to_bytes(11 * PAGE_SIZE), range);
+    assert!(range.truncate_to_bytes(0).is_empty());
+}
+
+#[test]
+fn range_sum_sizes() {
+    let frames = vec![
+        FrameRange::new(frame(0), frame(9)),
+        FrameRange::new(frame(0x100), frame(0x100)),
+        FrameRange::new(frame(0x200), frame(0x27F)),
+    ];
+    assert_eq!(frames.iter().sum::<usize>(), 10 + 1 + 0x80);
+    assert_eq!(frames.into_iter().sum::<usize>(), 10 + 1 + 0x80);
+
+    let pages = vec![PageRange::new(page(4), page(7)), PageRange::empty(), PageRange::new(page(20), page(21))];
+    assert_eq!(pages.into_iter().sum::<usize>(), 6);
+}
+
+#[test]
+fn range_contains_page_and_frame() {
+    let pages = PageRange::new(page(4), page(7));
+    assert!(pages.contains_page(page(4)));
+    assert!(pages.contains_page(page(7)));
+    assert!(!pages.contains_page(page(3)));
+    assert!(!pages.contains_page(page(8)));
+    assert!(!PageRange::empty().contains_page(page(0)));
+    assert!(!PageRange::empty().contains_page(page(1)));
+
+    let frames = FrameRange::new(frame(4), frame(7));
+    assert!(frames.contains_frame(frame(4)));
+    assert!(frames.contains_frame(frame(7)));
+    assert!(!frames.contains_frame(frame(8)));
+    assert!(!FrameRange::empty().contains_frame(frame(1)));
+}
+
+#[test]
+fn address_nonzero_conversions() {
+    assert_eq!(VirtualAddress::zero().as_nonzero(), None);
+    let virt = VirtualAddress::new_canonical(0xFFFF_8000_0000_1000);
+    let nz = virt.as_nonzero().unwrap();
+    assert_eq!(nz.get(), virt.value());
+    assert_eq!(VirtualAddress::from_nonzero(nz), virt);
+
+    assert_eq!(PhysicalAddress::zero().as_nonzero(), None);
+    let phys = PhysicalAddress::new_canonical(0x1000);
+    assert_eq!(phys.as_nonzero(), NonZeroUsize::new(0x1000));
+    assert_eq!(PhysicalAddress::from_nonzero(phys.as_nonzero().unwrap()), phys);
+    assert_eq!(core::mem::size_of::<Option<NonZeroUsize>>(), core::mem::size_of::<PhysicalAddress>());
+}
+
+#[test]
+fn frame_range_subtract_all() {
+    use heapless::consts::U8;
+    let region = FrameRange::new(frame(0x10), frame(0x4F));
+    let holes = [
+        FrameRange::new(frame(0x08), frame(0x11)), // overlaps the start edge
+        FrameRange::new(frame(0x20), frame(0x2F)),
+        FrameRange::empty(),
+        FrameRange::new(frame(0x31), frame(0x31)),
+        FrameRange::new(frame(0x4F), frame(0x60)), // overlaps the end edge
+    ];
+    let free = region.subtract_all::<U8>(&holes).unwrap();
+    assert_eq!(&free[..], &[
+        FrameRange::new(frame(0x12), frame(0x1F)),
+        FrameRange::new(frame(0x30), frame(0x30)),
+        FrameRange::new(frame(0x32), frame(0x4E)),
+    ][..]);
+
+    // no holes leaves the whole range, and a covering hole leaves nothing
+    assert_eq!(&region.subtract_all::<U8>(&[]).unwrap()[..], &[region.clone()][..]);
+    assert!(region.subtract_all::<U8>(&[FrameRange::new(frame(0), Frame::max())]).unwrap().is_empty());
+    assert!(FrameRange::empty().subtract_all::<U8>(&holes).unwrap().is_empty());
+    assert_eq!(region.subtract_all::<heapless::consts::U2>(&holes), Err(MemoryStructError::CapacityExceeded));
+}
+
+#[test]
+fn huge_page_arithmetic_and_iteration() {
+    let huge = |number: usize| Page::<Size2MiB> { number, size: PhantomData };
+    let start = Page::<Size2MiB>::containing_address_sized(VirtualAddress::new_canonical(0x40_0000));
+    assert_eq!(start, huge(2));
+    assert_eq!(start + 3, huge(5));
+    assert_eq!(start - 5, huge(0));
+    assert_eq!(start.checked_add(1).map(|p| p.start_address()), Some(VirtualAddress::new_canonical(0x60_0000)));
+    assert_eq!(huge(Size2MiB::MAX_NUMBER).checked_add(1), None);
+    assert_eq!(huge(Size2MiB::MAX_NUMBER) + 1, huge(Size2MiB::MAX_NUMBER));
+    assert_eq!(huge(7) - start, 5);
+    let pages: Vec<Page<Size2MiB>> = (start..=huge(4)).collect();
+    assert_eq!(pages, vec![huge(2), huge(3), huge(4)]);
+    assert_eq!(Size4KiB::MAX_NUMBER, MAX_PAGE_NUMBER);
+}
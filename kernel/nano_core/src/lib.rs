@@ -151,13 +151,13 @@ pub extern "C" fn nano_core_start(multiboot_information_virtual_address: usize)
             let ap_realmode_begin = try_exit!(
                 init_symbols.get("ap_start_realmode")
                     .ok_or("Missing expected symbol from assembly code \"ap_start_realmode\"")
-                    .and_then(|v| VirtualAddress::new(*v + KERNEL_OFFSET)
+                    .and_then(|v| VirtualAddress::new(*v + KERNEL_OFFSET).map_err(|e| e.into())
                 )
             );
             let ap_realmode_end   = try_exit!(
                 init_symbols.get("ap_start_realmode_end")
                     .ok_or("Missing expected symbol from assembly code \"ap_start_realmode_end\"")
-                    .and_then(|v| VirtualAddress::new(*v + KERNEL_OFFSET)
+                    .and_then(|v| VirtualAddress::new(*v + KERNEL_OFFSET).map_err(|e| e.into())
                 )
             );
             // debug!("ap_realmode_begin: {:#X}, ap_realmode_end: {:#X}", ap_realmode_begin, ap_realmode_end);